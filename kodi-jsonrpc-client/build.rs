@@ -0,0 +1,555 @@
+//! Generates bindings for every method and type described in `schema.json`, the output of
+//! `JSONRPC.Introspect`, into `$OUT_DIR/generated.rs` which is included by `src/generated.rs`.
+
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+
+const SCHEMA: &str = "schema.json";
+const METHODS: &str = "src/methods.rs";
+
+/// Methods which only read data without being named `Get*`
+const READ_ONLY: &[&str] = &[
+    "Files.PrepareDownload",
+    "JSONRPC.Introspect",
+    "JSONRPC.Permission",
+    "JSONRPC.Ping",
+    "JSONRPC.Version",
+];
+
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Rust items of a module and its submodules
+#[derive(Default)]
+struct Module {
+    items: String,
+    names: HashSet<String>,
+    children: BTreeMap<String, Module>,
+}
+
+impl Module {
+    fn get(&mut self, path: &[String]) -> &mut Module {
+        path.iter().fold(self, |module, name| {
+            module.children.entry(name.clone()).or_default()
+        })
+    }
+
+    fn write(&self, out: &mut String) {
+        out.push_str(&self.items);
+        for (name, child) in &self.children {
+            writeln!(out, "pub mod {} {{", name).unwrap();
+            child.write(out);
+            out.push_str("}\n");
+        }
+    }
+}
+
+struct Generator<'a> {
+    types: &'a Map<String, Value>,
+    root: Module,
+    /// Id of the schema type currently being generated, used to break recursive types
+    owner: Option<String>,
+}
+
+impl<'a> Generator<'a> {
+    fn new(types: &'a Map<String, Value>) -> Self {
+        Self {
+            types,
+            root: Module::default(),
+            owner: None,
+        }
+    }
+
+    /// Module path and name of the Rust type generated for a schema type id
+    fn type_location(id: &str) -> (Vec<String>, String) {
+        let mut segments: Vec<&str> = id.split('.').collect();
+        let name = segments.pop().unwrap();
+        let mut module = vec!["types".to_owned()];
+        module.extend(segments.iter().map(|segment| module_name(segment)));
+        (module, pascal_case(name))
+    }
+
+    fn type_path(id: &str) -> String {
+        let (module, name) = Self::type_location(id);
+        format!("crate::generated::{}::{}", module.join("::"), name)
+    }
+
+    /// Returns the definition of a schema type merged with every type it extends
+    fn resolve(&self, node: &Value) -> Map<String, Value> {
+        let mut resolved = Map::new();
+        let extends = match node.get("extends") {
+            Some(Value::String(id)) => vec![id.as_str()],
+            Some(Value::Array(ids)) => ids.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for id in extends {
+            for (key, value) in self.resolve(&self.types[id]) {
+                merge(&mut resolved, key, value);
+            }
+        }
+        if let Value::Object(object) = node {
+            for (key, value) in object {
+                if key != "extends" && key != "id" {
+                    merge(&mut resolved, key.clone(), value.clone());
+                }
+            }
+        }
+        resolved
+    }
+
+    /// Whether the schema type `from` contains `to`, other than through an array or a map
+    fn contains(&self, from: &str, to: &str, visited: &mut HashSet<String>) -> bool {
+        if from == to {
+            return true;
+        }
+        if !visited.insert(from.to_owned()) {
+            return false;
+        }
+        let mut refs = Vec::new();
+        direct_refs(&Value::Object(self.resolve(&self.types[from])), &mut refs);
+        refs.iter().any(|id| self.contains(id, to, visited))
+    }
+
+    fn is_recursive(&self, id: &str) -> bool {
+        match &self.owner {
+            Some(owner) => self.contains(id, owner, &mut HashSet::new()),
+            None => false,
+        }
+    }
+
+    fn add_item(&mut self, module: &[String], name: &str, item: String) {
+        let path = module.join("::");
+        let module = self.root.get(module);
+        if !module.names.insert(name.to_owned()) {
+            panic!("{}::{} is generated twice", path, name);
+        }
+        module.items.push_str(&item);
+    }
+
+    fn generate_type(&mut self, id: &str) {
+        let (module, name) = Self::type_location(id);
+        let node = Value::Object(self.resolve(&self.types[id]));
+        self.owner = Some(id.to_owned());
+        let ty = self.rust_type(&node, &module, &name);
+        if ty != name {
+            let mut item = doc(&node);
+            writeln!(item, "pub type {} = {};", name, ty).unwrap();
+            self.add_item(&module, &name, item);
+        }
+        self.owner = None;
+    }
+
+    fn generate_method(&mut self, id: &str, method: &Value) {
+        let module = vec!["methods".to_owned()];
+        let name = id.replace('.', "");
+        let mut fields = String::new();
+        let mut all_optional = true;
+        for param in method["params"].as_array().into_iter().flatten() {
+            let param_name = param["name"].as_str().unwrap();
+            let hint = format!("{}{}", name, pascal_case(param_name));
+            let ty = self.rust_type(param, &module, &hint);
+            let required = param["required"].as_bool().unwrap_or(false);
+            all_optional &= !required;
+            fields.push_str(&field(param_name, param, ty, required));
+        }
+        let response = self.rust_type(&method["returns"], &module, &format!("{}Response", name));
+
+        let mut item = doc(method);
+        item.push_str(if all_optional {
+            "#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]\n"
+        } else {
+            "#[derive(Clone, Debug, PartialEq, serde::Serialize)]\n"
+        });
+        writeln!(item, "pub struct {} {{\n{}}}", name, fields).unwrap();
+        writeln!(
+            item,
+            "impl crate::KodiMethod for {} {{\n    const NAME: &'static str = {:?};\n    const IDEMPOTENT: bool = {};\n    type Response = {};\n}}",
+            name,
+            id,
+            is_idempotent(id),
+            response
+        )
+        .unwrap();
+        self.add_item(&module, &name, item);
+    }
+
+    /// Returns the Rust type of a schema node, generating a new item named `hint` in `module`
+    /// if it needs its own definition.
+    fn rust_type(&mut self, node: &Value, module: &[String], hint: &str) -> String {
+        if let Some(id) = node.get("$ref").and_then(Value::as_str) {
+            return Self::type_path(id);
+        }
+        match node.get("type") {
+            Some(Value::String(kind)) => match kind.as_str() {
+                "boolean" => "bool".to_owned(),
+                "integer" => "i64".to_owned(),
+                "number" => "f64".to_owned(),
+                "string" => match node.get("enums").and_then(Value::as_array) {
+                    Some(values) => self.generate_enum(node, values, module, hint),
+                    None => "std::string::String".to_owned(),
+                },
+                "array" => self.array_type(node, module, hint),
+                "object" => self.object_type(node, module, hint),
+                _ => "serde_json::Value".to_owned(),
+            },
+            Some(Value::Array(variants)) => self.generate_union(node, variants, module, hint),
+            _ if node.get("properties").is_some() => self.object_type(node, module, hint),
+            _ if node.get("items").is_some() => self.array_type(node, module, hint),
+            _ => "serde_json::Value".to_owned(),
+        }
+    }
+
+    fn array_type(&mut self, node: &Value, module: &[String], hint: &str) -> String {
+        match node.get("items") {
+            Some(items @ Value::Object(_)) => {
+                format!(
+                    "Vec<{}>",
+                    self.rust_type(items, module, &format!("{}Item", hint))
+                )
+            }
+            _ => "Vec<serde_json::Value>".to_owned(),
+        }
+    }
+
+    fn map_type(&mut self, node: &Value, module: &[String], hint: &str) -> Option<String> {
+        match node.get("additionalProperties") {
+            Some(value @ Value::Object(_)) => Some(format!(
+                "std::collections::HashMap<std::string::String, {}>",
+                self.rust_type(value, module, &format!("{}Value", hint))
+            )),
+            _ => None,
+        }
+    }
+
+    fn object_type(&mut self, node: &Value, module: &[String], hint: &str) -> String {
+        let properties = match node.get("properties").and_then(Value::as_object) {
+            Some(properties) => properties,
+            None => {
+                return self.map_type(node, module, hint).unwrap_or_else(|| {
+                    "serde_json::Map<std::string::String, serde_json::Value>".to_owned()
+                })
+            }
+        };
+        let mut fields = String::new();
+        for (name, property) in properties {
+            let mut ty =
+                self.rust_type(property, module, &format!("{}{}", hint, pascal_case(name)));
+            if let Some(id) = property.get("$ref").and_then(Value::as_str) {
+                if self.is_recursive(id) {
+                    ty = format!("Box<{}>", ty);
+                }
+            }
+            let required = property["required"].as_bool().unwrap_or(false);
+            fields.push_str(&field(name, property, ty, required));
+        }
+        if let Some(map) = self.map_type(node, module, hint) {
+            writeln!(fields, "    #[serde(flatten)]\n    pub other: {},", map).unwrap();
+        }
+
+        let mut item = doc(node);
+        item.push_str("#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]\n");
+        writeln!(item, "pub struct {} {{\n{}}}", hint, fields).unwrap();
+        self.add_item(module, hint, item);
+        hint.to_owned()
+    }
+
+    fn generate_enum(
+        &mut self,
+        node: &Value,
+        values: &[Value],
+        module: &[String],
+        hint: &str,
+    ) -> String {
+        let mut names = HashSet::new();
+        let mut variants = String::new();
+        for value in values.iter().filter_map(Value::as_str) {
+            let name = unique(&mut names, pascal_case(value));
+            writeln!(
+                variants,
+                "    #[serde(rename = {:?})]\n    {},",
+                value, name
+            )
+            .unwrap();
+        }
+
+        let mut item = doc(node);
+        item.push_str(
+            "#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]\n",
+        );
+        writeln!(item, "pub enum {} {{\n{}}}", hint, variants).unwrap();
+        self.add_item(module, hint, item);
+        hint.to_owned()
+    }
+
+    fn generate_union(
+        &mut self,
+        node: &Value,
+        variants: &[Value],
+        module: &[String],
+        hint: &str,
+    ) -> String {
+        // Variants are tried in order so the most specific objects must come first
+        let mut variants: Vec<&Value> = variants.iter().collect();
+        variants.sort_by_key(|variant| std::cmp::Reverse(required_count(variant)));
+
+        let mut names = HashSet::new();
+        let mut body = String::new();
+        for variant in variants {
+            let name = unique(&mut names, variant_name(variant));
+            body.push_str(&doc(variant).replace("#[doc", "    #[doc"));
+            if variant.get("type").and_then(Value::as_str) == Some("null") {
+                writeln!(body, "    {},", name).unwrap();
+                continue;
+            }
+            let mut ty = self.rust_type(variant, module, &format!("{}{}", hint, name));
+            if let Some(id) = variant.get("$ref").and_then(Value::as_str) {
+                if self.is_recursive(id) {
+                    ty = format!("Box<{}>", ty);
+                }
+            }
+            writeln!(body, "    {}({}),", name, ty).unwrap();
+        }
+
+        let mut item = doc(node);
+        item.push_str("#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]\n");
+        item.push_str("#[serde(untagged)]\n");
+        writeln!(item, "pub enum {} {{\n{}}}", hint, body).unwrap();
+        self.add_item(module, hint, item);
+        hint.to_owned()
+    }
+}
+
+/// Whether a method only reads data, so that it can be retried like the hand-written getters
+fn is_idempotent(id: &str) -> bool {
+    let name = id.rsplit('.').next().unwrap();
+    name.starts_with("Get") || READ_ONLY.contains(&id)
+}
+
+/// Returns the names of the methods defined with `define_method!` in `src/methods.rs`
+fn hand_written_methods() -> Vec<String> {
+    let source = std::fs::read_to_string(METHODS).expect("could not read hand-written methods");
+    source
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_start();
+            let line = line.strip_prefix("idempotent ").unwrap_or(line);
+            let (id, rest) = line.split_once(' ')?;
+            let (namespace, method) = id.split_once('.')?;
+            let is_ident =
+                |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric());
+            (rest.starts_with('{') && is_ident(namespace) && is_ident(method))
+                .then(|| id.to_owned())
+        })
+        .collect()
+}
+
+/// Returns a test checking that the hand-written methods also described by the schema agree
+/// with the generated ones
+fn hand_written_test(methods: &Map<String, Value>) -> String {
+    let mut test = String::from("#[test]\nfn test_hand_written_bindings() {\n");
+    for id in hand_written_methods() {
+        if !methods.contains_key(&id) {
+            continue;
+        }
+        let name = id.replace('.', "");
+        for constant in ["NAME", "IDEMPOTENT"] {
+            writeln!(
+                test,
+                "    assert_eq!(<crate::methods::{name} as crate::KodiMethod>::{constant}, <methods::{name} as crate::KodiMethod>::{constant}, {id:?});",
+                name = name,
+                constant = constant,
+                id = id,
+            )
+            .unwrap();
+        }
+    }
+    test.push_str("}\n");
+    test
+}
+
+/// Merges a key of a schema type into the definition of the type extending it
+fn merge(resolved: &mut Map<String, Value>, key: String, value: Value) {
+    match (resolved.get_mut(&key), value) {
+        (Some(Value::Object(properties)), Value::Object(overrides)) if key == "properties" => {
+            properties.extend(overrides)
+        }
+        (_, value) => {
+            resolved.insert(key, value);
+        }
+    }
+}
+
+/// Collects the types referenced by a node, other than through an array or a map
+fn direct_refs(node: &Value, refs: &mut Vec<String>) {
+    match node {
+        Value::Object(object) => {
+            if let Some(Value::String(id)) = object.get("$ref") {
+                refs.push(id.clone());
+            }
+            for (key, value) in object {
+                if key != "items" && key != "additionalProperties" {
+                    direct_refs(value, refs);
+                }
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|value| direct_refs(value, refs)),
+        _ => {}
+    }
+}
+
+fn required_count(node: &Value) -> usize {
+    node.get("properties")
+        .and_then(Value::as_object)
+        .map(|properties| {
+            properties
+                .values()
+                .filter(|property| property["required"].as_bool().unwrap_or(false))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+fn variant_name(node: &Value) -> String {
+    if let Some(id) = node.get("$ref").and_then(Value::as_str) {
+        return pascal_case(id.rsplit('.').next().unwrap());
+    }
+    if let Some(properties) = node.get("properties").and_then(Value::as_object) {
+        // Name objects after their first required property, which usually identifies them
+        let required = properties
+            .iter()
+            .find(|(_, property)| property["required"].as_bool().unwrap_or(false))
+            .or_else(|| properties.iter().next());
+        if let Some((name, _)) = required {
+            return pascal_case(name);
+        }
+    }
+    match node.get("type").and_then(Value::as_str) {
+        Some(kind) => pascal_case(kind),
+        None => "Value".to_owned(),
+    }
+}
+
+fn field(name: &str, node: &Value, ty: String, required: bool) -> String {
+    let ident = field_name(name);
+    let mut attrs = Vec::new();
+    if ident.trim_start_matches("r#") != name {
+        attrs.push(format!("rename = {:?}", name));
+    }
+    let ty = if required {
+        ty
+    } else {
+        attrs.push("default".to_owned());
+        attrs.push("skip_serializing_if = \"Option::is_none\"".to_owned());
+        format!("Option<{}>", ty)
+    };
+    let mut field = doc(node).replace("#[doc", "    #[doc");
+    if !attrs.is_empty() {
+        writeln!(field, "    #[serde({})]", attrs.join(", ")).unwrap();
+    }
+    writeln!(field, "    pub {}: {},", ident, ty).unwrap();
+    field
+}
+
+fn doc(node: &Value) -> String {
+    match node.get("description").and_then(Value::as_str) {
+        Some(description) => format!("#[doc = {:?}]\n", description),
+        None => String::new(),
+    }
+}
+
+fn unique(names: &mut HashSet<String>, name: String) -> String {
+    let mut candidate = name.clone();
+    let mut index = 2;
+    while !names.insert(candidate.clone()) {
+        candidate = format!("{}{}", name, index);
+        index += 1;
+    }
+    candidate
+}
+
+fn pascal_case(name: &str) -> String {
+    let mut result = String::new();
+    for part in name.split(|c: char| !c.is_ascii_alphanumeric()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            result.push(first.to_ascii_uppercase());
+            result.extend(chars);
+        }
+    }
+    if result.is_empty() {
+        "Empty".to_owned()
+    } else if result.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("V{}", result)
+    } else {
+        result
+    }
+}
+
+fn module_name(segment: &str) -> String {
+    escape(segment.to_ascii_lowercase())
+}
+
+fn field_name(name: &str) -> String {
+    let mut ident: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    escape(ident)
+}
+
+fn escape(ident: String) -> String {
+    if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{}", ident)
+    } else if ["self", "super", "crate", "Self"].contains(&ident.as_str()) {
+        format!("{}_", ident)
+    } else {
+        ident
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", SCHEMA);
+    println!("cargo:rerun-if-changed={}", METHODS);
+
+    let schema: Value =
+        serde_json::from_str(&std::fs::read_to_string(SCHEMA).expect("could not read Kodi schema"))
+            .expect("could not parse Kodi schema");
+    let types = schema["types"].as_object().expect("schema has no types");
+    let methods = schema["methods"]
+        .as_object()
+        .expect("schema has no methods");
+
+    let mut generator = Generator::new(types);
+    for id in types.keys() {
+        generator.generate_type(id);
+    }
+    for (id, method) in methods {
+        generator.generate_method(id, method);
+    }
+
+    let mut out = format!(
+        "/// Version of the Kodi JSON-RPC API the bindings have been generated from\npub const VERSION: &str = {:?};\n",
+        schema["version"].as_str().unwrap_or_default()
+    );
+    generator.root.write(&mut out);
+    out.push_str(&hand_written_test(methods));
+    let path = Path::new(&std::env::var("OUT_DIR").unwrap()).join("generated.rs");
+    std::fs::write(path, out).expect("could not write generated bindings");
+}
@@ -0,0 +1,228 @@
+use crate::capabilities::KodiCapabilities;
+use crate::{KodiClient, KodiError, KodiMethod, KodiRequest, KodiResponse, KodiTransport};
+use std::collections::HashMap;
+
+/// Several methods sent to Kodi in a single JSON-RPC batch request.
+///
+/// Methods are added one by one with `add` and the batch is sent with `send`, which returns
+/// the result of each method in a tuple, in the order they were added.
+pub struct KodiBatch<'a, T: KodiTransport, L> {
+    client: &'a KodiClient<T>,
+    methods: L,
+}
+
+impl<'a, T: KodiTransport> KodiBatch<'a, T, ()> {
+    pub(crate) fn new(client: &'a KodiClient<T>) -> Self {
+        Self {
+            client,
+            methods: (),
+        }
+    }
+}
+
+impl<'a, T: KodiTransport, L> KodiBatch<'a, T, L> {
+    #[allow(clippy::should_implement_trait)]
+    pub fn add<M: KodiMethod>(self, method: M) -> KodiBatch<'a, T, L::Output>
+    where
+        L: KodiBatchAdd<M>,
+    {
+        KodiBatch {
+            client: self.client,
+            methods: self.methods.add(method),
+        }
+    }
+}
+
+impl<'a, T: KodiTransport, L: KodiBatchMethods> KodiBatch<'a, T, L> {
+    /// Sends the batch and returns the result of each method.
+    ///
+    /// The outer error is returned when the batch as a whole could not be sent or its
+    /// response could not be read.
+    pub async fn send(self) -> Result<L::Responses, KodiError> {
+        if let Some(capabilities) = &self.client.capabilities {
+            self.methods.check(capabilities)?;
+        }
+        let methods = &self.methods;
+        let (first_id, text) = self
+            .client
+            .send_body(&methods.names(), L::IDEMPOTENT, L::LEN, |first_id| {
+                let requests = methods.serialize(first_id)?;
+                serde_json::to_string(&requests).map_err(|error| KodiError::RequestSerialization {
+                    method: methods.names(),
+                    error,
+                })
+            })
+            .await?;
+        let responses: Vec<serde_json::Value> = match serde_json::from_str(&text) {
+            Ok(responses) => responses,
+            Err(error) => {
+                // Kodi answers a batch it could not read with a single error
+                let response = serde_json::from_str::<KodiResponse<serde_json::Value>>(&text);
+                if let Ok(response) = response {
+                    response.into_result(&self.methods.names())?;
+                }
+                return Err(KodiError::ResponseDeserialization {
+                    method: self.methods.names(),
+                    error,
+                    payload: text,
+                });
+            }
+        };
+        let mut responses = responses
+            .into_iter()
+            .filter_map(|response| Some((response.get("id")?.as_u64()? as usize, response)))
+            .collect();
+        Ok(self.methods.deserialize(first_id, &mut responses))
+    }
+}
+
+/// Tuple of methods that can be sent in a batch
+pub trait KodiBatchMethods: std::fmt::Debug {
+    /// Tuple of the result of each method
+    type Responses;
+    const LEN: usize;
+    /// Whether all the methods are idempotent, so that the batch can be retried
+    const IDEMPOTENT: bool;
+
+    /// Names of the methods, separated by commas
+    fn names(&self) -> String;
+    fn check(&self, capabilities: &KodiCapabilities) -> Result<(), KodiError>;
+    fn serialize(&self, first_id: usize) -> Result<Vec<serde_json::Value>, KodiError>;
+    fn deserialize(
+        &self,
+        first_id: usize,
+        responses: &mut HashMap<usize, serde_json::Value>,
+    ) -> Self::Responses;
+}
+
+/// Tuple of methods that another method can be appended to
+pub trait KodiBatchAdd<M: KodiMethod> {
+    type Output;
+
+    fn add(self, method: M) -> Self::Output;
+}
+
+macro_rules! impl_batch_methods {
+    ($( $method:ident $index:tt ),*) => {
+        impl<$( $method: KodiMethod ),*> KodiBatchMethods for ($( $method, )*) {
+            type Responses = ($( Result<$method::Response, KodiError>, )*);
+            const LEN: usize = [$( $index ),*].len();
+            const IDEMPOTENT: bool = $( $method::IDEMPOTENT && )* true;
+
+            fn names(&self) -> String {
+                [$( $method::NAME ),*].join(", ")
+            }
+
+            fn check(&self, capabilities: &KodiCapabilities) -> Result<(), KodiError> {
+                $( capabilities.check(&self.$index)?; )*
+                Ok(())
+            }
+
+            fn serialize(&self, first_id: usize) -> Result<Vec<serde_json::Value>, KodiError> {
+                Ok(vec![$( KodiRequest::new(&self.$index, first_id + $index).to_value()? ),*])
+            }
+
+            fn deserialize(
+                &self,
+                first_id: usize,
+                responses: &mut HashMap<usize, serde_json::Value>,
+            ) -> Self::Responses {
+                ($(
+                    KodiRequest::new(&self.$index, first_id + $index)
+                        .batch_result(responses.remove(&(first_id + $index))),
+                )*)
+            }
+        }
+    };
+}
+
+macro_rules! impl_batch_add {
+    ($( $method:ident $index:tt ),* ; $next:ident) => {
+        impl<$( $method: KodiMethod, )* $next: KodiMethod> KodiBatchAdd<$next> for ($( $method, )*) {
+            type Output = ($( $method, )* $next,);
+
+            fn add(self, method: $next) -> Self::Output {
+                ($( self.$index, )* method,)
+            }
+        }
+    };
+}
+
+impl_batch_methods!(A 0);
+impl_batch_methods!(A 0, B 1);
+impl_batch_methods!(A 0, B 1, C 2);
+impl_batch_methods!(A 0, B 1, C 2, D 3);
+impl_batch_methods!(A 0, B 1, C 2, D 3, E 4);
+impl_batch_methods!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_batch_methods!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_batch_methods!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+impl_batch_add!(; A);
+impl_batch_add!(A 0; B);
+impl_batch_add!(A 0, B 1; C);
+impl_batch_add!(A 0, B 1, C 2; D);
+impl_batch_add!(A 0, B 1, C 2, D 3; E);
+impl_batch_add!(A 0, B 1, C 2, D 3, E 4; F);
+impl_batch_add!(A 0, B 1, C 2, D 3, E 4, F 5; G);
+impl_batch_add!(A 0, B 1, C 2, D 3, E 4, F 5, G 6; H);
+
+#[tokio::test]
+async fn test_batch() {
+    use crate::methods::{ApplicationSetVolume, JSONRPCVersion, JSONRPCVersionResponse};
+    use crate::{KodiErrorCode, KodiTransportError};
+    use async_trait::async_trait;
+
+    /// Answers batches in reverse order, failing `Application.SetVolume`
+    struct BatchTransport;
+
+    #[async_trait]
+    impl KodiTransport for BatchTransport {
+        async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+            let requests: Vec<serde_json::Value> = serde_json::from_str(&body).unwrap();
+            assert_eq!(requests[0]["id"], id);
+            let responses: Vec<_> = requests
+                .iter()
+                .rev()
+                .map(|request| match request["method"].as_str().unwrap() {
+                    "JSONRPC.Version" => format!(
+                        r#"{{"id":{},"jsonrpc":"2.0","result":{{"version":{{"major":12,"minor":4,"patch":0}}}}}}"#,
+                        request["id"]
+                    ),
+                    "Application.SetVolume" => format!(
+                        r#"{{"error":{{"code":-32602,"data":{{"method":"Application.SetVolume","stack":{{"message":"Received value does not match any of the union type definitions","name":"volume","type":["integer","string"]}}}},"message":"Invalid params."}},"id":{},"jsonrpc":"2.0"}}"#,
+                        request["id"]
+                    ),
+                    method => panic!("unexpected method {}", method),
+                })
+                .collect();
+            Ok(format!("[{}]", responses.join(",")))
+        }
+    }
+
+    let client = KodiClient::with_transport(BatchTransport);
+    let (version, volume, other) = client
+        .batch()
+        .add(JSONRPCVersion {})
+        .add(ApplicationSetVolume { volume: 42 })
+        .add(JSONRPCVersion {})
+        .send()
+        .await
+        .unwrap();
+    let JSONRPCVersionResponse::Version { major, minor, .. } = version.unwrap();
+    assert_eq!((major, minor), (12, 4));
+    match volume {
+        Err(KodiError::Jsonrpc {
+            method,
+            code: KodiErrorCode::InvalidParams,
+            data: Some(data),
+            ..
+        }) => {
+            assert_eq!(method, "Application.SetVolume");
+            let stack = data.stack.unwrap();
+            assert_eq!(stack.name, "volume");
+            assert_eq!(stack.kind, vec!["integer", "string"]);
+        }
+        volume => panic!("unexpected result {:?}", volume),
+    }
+    assert!(other.is_ok());
+}
@@ -0,0 +1,304 @@
+use crate::methods::{
+    JSONRPCIntrospect, JSONRPCIntrospectResponse, JSONRPCPermission, JSONRPCPermissionResponse,
+    JSONRPCVersion, JSONRPCVersionResponse,
+};
+use crate::{KodiClient, KodiError, KodiMethod, KodiTransport};
+use serde_json::Value;
+
+/// Part of a method call that the connected Kodi does not support
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Unsupported {
+    Method,
+    Parameter(String),
+    Property {
+        parameter: String,
+        property: String,
+    },
+    /// The client has not been granted the permission the method requires, such as `ControlPower`
+    Permission(String),
+}
+
+impl std::fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Method => write!(f, "method is not supported"),
+            Self::Parameter(parameter) => write!(f, "parameter {} is not supported", parameter),
+            Self::Property {
+                parameter,
+                property,
+            } => write!(
+                f,
+                "property {} of parameter {} is not supported",
+                property, parameter
+            ),
+            Self::Permission(permission) => write!(f, "permission {} is not granted", permission),
+        }
+    }
+}
+
+/// What the connected Kodi supports, as reported by `JSONRPC.Version`, `JSONRPC.Introspect`
+/// and `JSONRPC.Permission`.
+///
+/// Once attached to a client with [`KodiClient::with_capabilities`], methods are checked before
+/// being sent and the ones Kodi would reject fail early with [`KodiError::Unsupported`].
+#[derive(Clone, Debug)]
+pub struct KodiCapabilities {
+    version: (usize, usize, usize),
+    schema: JSONRPCIntrospectResponse,
+    permissions: JSONRPCPermissionResponse,
+}
+
+impl KodiCapabilities {
+    pub fn new(
+        version: JSONRPCVersionResponse,
+        schema: JSONRPCIntrospectResponse,
+        permissions: JSONRPCPermissionResponse,
+    ) -> Self {
+        let JSONRPCVersionResponse::Version {
+            major,
+            minor,
+            patch,
+        } = version;
+        Self {
+            version: (major, minor, patch),
+            schema,
+            permissions,
+        }
+    }
+
+    /// Retrieves the capabilities of the Kodi a client is connected to, in a single batch
+    pub async fn fetch<T: KodiTransport>(client: &KodiClient<T>) -> Result<Self, KodiError> {
+        let (version, schema, permissions) = client
+            .batch()
+            .add(JSONRPCVersion {})
+            .add(JSONRPCIntrospect::all())
+            .add(JSONRPCPermission {})
+            .send()
+            .await?;
+        Ok(Self::new(version?, schema?, permissions?))
+    }
+
+    /// Version of the JSON-RPC API as `(major, minor, patch)`
+    pub fn version(&self) -> (usize, usize, usize) {
+        self.version
+    }
+
+    pub fn permissions(&self) -> &JSONRPCPermissionResponse {
+        &self.permissions
+    }
+
+    /// Whether the client has been granted `permission`, named like in the introspection
+    /// result (`ReadData`, `ControlPower`, ...)
+    pub fn has_permission(&self, permission: &str) -> bool {
+        let permissions = &self.permissions;
+        match permission.to_ascii_lowercase().as_str() {
+            "controlgui" => permissions.controlgui,
+            "controlnotify" => permissions.controlnotify,
+            "controlplayback" => permissions.controlplayback,
+            "controlpower" => permissions.controlpower,
+            "controlpvr" => permissions.controlpvr,
+            "controlsystem" => permissions.controlsystem,
+            "executeaddon" => permissions.executeaddon,
+            "manageaddon" => permissions.manageaddon,
+            "navigate" => permissions.navigate,
+            "readdata" => permissions.readdata,
+            "removedata" => permissions.removedata,
+            "updatedata" => permissions.updatedata,
+            "writefile" => permissions.writefile,
+            // Kodi is the judge of permissions this client does not know about
+            _ => true,
+        }
+    }
+
+    pub fn has_method(&self, method: &str) -> bool {
+        self.schema.methods.contains_key(method)
+    }
+
+    pub fn has_parameter(&self, method: &str, parameter: &str) -> bool {
+        self.schema
+            .methods
+            .get(method)
+            .into_iter()
+            .flat_map(|method| &method.params)
+            .any(|param| param.name == parameter)
+    }
+
+    /// Whether `property` is one of the values of an enumeration type, such as `Audio.Fields.Song`,
+    /// or one of the properties of an object type, such as `Audio.Details.Song`.
+    pub fn has_property(&self, kind: &str, property: &str) -> bool {
+        match self.schema.types.get(kind) {
+            Some(schema) => self.schema_has_property(schema, property),
+            None => false,
+        }
+    }
+
+    fn schema_has_property(&self, schema: &Value, property: &str) -> bool {
+        let has_enum = |schema: &Value| {
+            schema["enums"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|value| value == property)
+        };
+        if has_enum(schema) || has_enum(&schema["items"]) {
+            return true;
+        }
+        if schema["properties"].get(property).is_some() {
+            return true;
+        }
+        // Properties are inherited from the types being extended
+        match &schema["extends"] {
+            Value::String(kind) => self.has_property(kind, property),
+            Value::Array(kinds) => kinds
+                .iter()
+                .filter_map(Value::as_str)
+                .any(|kind| self.has_property(kind, property)),
+            _ => false,
+        }
+    }
+
+    /// Checks that the method, all its parameters and the properties it requests are supported,
+    /// and that the client has been granted the permission the method requires
+    pub fn check<M: KodiMethod>(&self, method: &M) -> Result<(), KodiError> {
+        let unsupported = |feature| KodiError::Unsupported {
+            method: M::NAME.to_owned(),
+            version: self.version,
+            feature,
+        };
+        let schema = self
+            .schema
+            .methods
+            .get(M::NAME)
+            .ok_or_else(|| unsupported(Unsupported::Method))?;
+        if let Some(permission) = &schema.permission {
+            if !self.has_permission(permission) {
+                return Err(unsupported(Unsupported::Permission(permission.clone())));
+            }
+        }
+        let params = match serde_json::to_value(method) {
+            Ok(Value::Object(params)) => params,
+            // Serialization errors are reported when the request is actually sent
+            _ => return Ok(()),
+        };
+        for (name, value) in params.iter().filter(|(_, value)| !value.is_null()) {
+            let param = schema
+                .params
+                .iter()
+                .find(|param| &param.name == name)
+                .ok_or_else(|| unsupported(Unsupported::Parameter(name.clone())))?;
+            let kind = match param.schema.get("$ref").and_then(Value::as_str) {
+                Some(kind) => kind,
+                None => continue,
+            };
+            for property in value.as_array().into_iter().flatten() {
+                if let Some(property) = property.as_str() {
+                    if !self.has_property(kind, property) {
+                        return Err(unsupported(Unsupported::Property {
+                            parameter: name.clone(),
+                            property: property.to_owned(),
+                        }));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_capabilities() {
+    use crate::methods::{ApplicationSetVolume, AudioLibraryGetProperties, AudioLibraryGetSongs};
+    use crate::types::audio::fields::Song;
+
+    // The shipped schema is the output of JSONRPC.Introspect
+    let schema = serde_json::from_str(include_str!("../schema.json")).unwrap();
+    let permissions = serde_json::from_value(serde_json::json!({
+        "controlgui": true, "controlnotify": true, "controlplayback": true, "controlpower": false,
+        "controlpvr": true, "controlsystem": true, "executeaddon": true, "manageaddon": true,
+        "navigate": true, "readdata": true, "removedata": true, "updatedata": true,
+        "writefile": true,
+    }))
+    .unwrap();
+    let capabilities = KodiCapabilities::new(
+        JSONRPCVersionResponse::Version {
+            major: 10,
+            minor: 3,
+            patch: 0,
+        },
+        schema,
+        permissions,
+    );
+
+    assert_eq!(capabilities.version(), (10, 3, 0));
+    assert!(!capabilities.permissions().controlpower);
+    assert!(capabilities.has_method("AudioLibrary.GetSongs"));
+    assert!(capabilities.has_parameter("AudioLibrary.GetSongs", "allroles"));
+    assert!(!capabilities.has_parameter("AudioLibrary.GetSongs", "foo"));
+    assert!(capabilities.has_property("Audio.Fields.Song", "albumartist"));
+    assert!(!capabilities.has_property("Audio.Fields.Song", "foo"));
+    // Inherited from Item.Details.Base
+    assert!(capabilities.has_property("Audio.Details.Song", "label"));
+
+    assert!(capabilities
+        .check(&AudioLibraryGetSongs::all_properties())
+        .is_ok());
+    let mut songs = AudioLibraryGetSongs::all_properties();
+    songs.properties = enumset::EnumSet::only(Song::Title);
+    assert!(capabilities.check(&songs).is_ok());
+    let properties = AudioLibraryGetProperties::supported(&capabilities);
+    assert_eq!(
+        properties.properties,
+        AudioLibraryGetProperties::all().properties
+    );
+    assert!(capabilities.check(&properties).is_ok());
+    // The shipped schema only describes the methods returning data
+    assert!(matches!(
+        capabilities.check(&ApplicationSetVolume { volume: 42 }),
+        Err(KodiError::Unsupported {
+            feature: Unsupported::Method,
+            version: (10, 3, 0),
+            ..
+        })
+    ));
+
+    // Introspected with metadata, methods come with the permission they require
+    let mut schema: JSONRPCIntrospectResponse =
+        serde_json::from_str(include_str!("../schema.json")).unwrap();
+    let set_volume = serde_json::from_value(serde_json::json!({
+        "params": [{"name": "volume", "type": "integer", "required": true}],
+        "permission": "ControlPlayback",
+        "returns": {"type": "integer"},
+    }))
+    .unwrap();
+    schema
+        .methods
+        .insert("Application.SetVolume".to_owned(), set_volume);
+    schema
+        .methods
+        .get_mut("AudioLibrary.GetSongs")
+        .unwrap()
+        .permission = Some("ReadData".to_owned());
+    let mut permissions = *capabilities.permissions();
+    permissions.controlplayback = false;
+    let capabilities = KodiCapabilities::new(
+        JSONRPCVersionResponse::Version {
+            major: 10,
+            minor: 3,
+            patch: 0,
+        },
+        schema,
+        permissions,
+    );
+    assert!(capabilities.has_permission("ReadData"));
+    assert!(!capabilities.has_permission("ControlPlayback"));
+    assert!(capabilities
+        .check(&AudioLibraryGetSongs::all_properties())
+        .is_ok());
+    assert!(matches!(
+        capabilities.check(&ApplicationSetVolume { volume: 42 }),
+        Err(KodiError::Unsupported {
+            feature: Unsupported::Permission(permission),
+            ..
+        }) if permission == "ControlPlayback"
+    ));
+}
@@ -0,0 +1,385 @@
+use crate::types::audio::details::{Album, Artist, Song};
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// Error returned when a library export cannot be parsed
+#[derive(Debug)]
+pub enum ExportError {
+    Xml(quick_xml::Error),
+    /// An element holds a value of the wrong type, such as a year that is not a number
+    InvalidValue {
+        element: String,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Xml(error) => write!(f, "{}", error),
+            Self::InvalidValue { element, value } => {
+                write!(f, "invalid value {:?} for element {}", value, element)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExportError {}
+
+impl From<quick_xml::Error> for ExportError {
+    fn from(error: quick_xml::Error) -> Self {
+        Self::Xml(error)
+    }
+}
+
+/// Music library exported to a single `kodi_music.xml` file by `AudioLibrary.Export`.
+///
+/// Library ids are not part of the export and are left to 0. Songs are exported as album tracks
+/// holding only their title, duration, MusicBrainz track id and position, from which both the
+/// disc and track numbers are decoded. Their album, album artists and year are the ones of
+/// their album, while details such as their own artists, genres, MusicBrainz artist ids, file
+/// or play count are left empty: use
+/// [`Song::exported_only`](crate::types::audio::changes::Song::exported_only) to ignore them
+/// when diffing songs against the live library.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MusicLibrary {
+    pub albums: Vec<Album>,
+    pub artists: Vec<Artist>,
+    /// Tracks of all the albums, in order
+    pub songs: Vec<Song>,
+}
+
+impl MusicLibrary {
+    pub fn parse(xml: &str) -> Result<Self, ExportError> {
+        let mut reader = Reader::from_str(xml);
+        reader.trim_text(true);
+        let mut buf = Vec::new();
+        // Names of the elements from the root `musicdb` one down to the current one
+        let mut path: Vec<String> = Vec::new();
+        let mut elements = Vec::new();
+        // Tracks of the current album, which are only complete once the album has been read
+        let mut tracks = Vec::new();
+        let mut library = Self::default();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(element) => {
+                    path.push(String::from_utf8_lossy(element.name()).into_owned())
+                }
+                Event::Text(text) if path.len() > 2 => {
+                    let text = text.unescape_and_decode(&reader)?;
+                    elements.push((path[2..].join("/"), text));
+                }
+                Event::CData(text) if path.len() > 2 => {
+                    let text = String::from_utf8_lossy(&text).into_owned();
+                    elements.push((path[2..].join("/"), text));
+                }
+                Event::End(_) => {
+                    if path.len() == 3 && path[1] == "album" && path[2] == "track" {
+                        let (track, album) = std::mem::take(&mut elements)
+                            .into_iter()
+                            .partition(|(element, _)| element.starts_with("track/"));
+                        elements = album;
+                        tracks.push(Elements(
+                            track
+                                .into_iter()
+                                .map(|(element, text)| (element["track/".len()..].to_owned(), text))
+                                .collect(),
+                        ));
+                    } else if path.len() == 2 {
+                        let item = Elements(std::mem::take(&mut elements));
+                        match path[1].as_str() {
+                            "album" => {
+                                let album = item.album()?;
+                                for track in tracks.drain(..) {
+                                    library.songs.push(track.song(&album)?);
+                                }
+                                library.albums.push(album);
+                            }
+                            "artist" => library.artists.push(item.artist()?),
+                            _ => {}
+                        }
+                    }
+                    path.pop();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(library)
+    }
+}
+
+/// Text of the elements of an exported item, along with their path relative to the item, such
+/// as `albumArtistCredits/artist`
+struct Elements(Vec<(String, String)>);
+
+impl Elements {
+    fn first(&self, path: &str) -> Option<String> {
+        self.0
+            .iter()
+            .find(|(element, _)| element == path)
+            .map(|(_, text)| text.clone())
+    }
+
+    fn all(&self, path: &str) -> Vec<String> {
+        self.0
+            .iter()
+            .filter(|(element, _)| element == path)
+            .map(|(_, text)| text.clone())
+            .collect()
+    }
+
+    fn non_empty(&self, path: &str) -> Option<Vec<String>> {
+        Some(self.all(path)).filter(|texts| !texts.is_empty())
+    }
+
+    fn parse<T: std::str::FromStr>(&self, path: &str) -> Result<Option<T>, ExportError> {
+        self.first(path)
+            .map(|text| {
+                text.parse().map_err(|_| ExportError::InvalidValue {
+                    element: path.to_owned(),
+                    value: text,
+                })
+            })
+            .transpose()
+    }
+
+    fn album(&self) -> Result<Album, ExportError> {
+        let title = self.first("title");
+        // Recent versions of Kodi only export the release date
+        let year = match self.parse("year")? {
+            Some(year) => Some(year),
+            None => self
+                .first("releasedate")
+                .and_then(|date| date.get(..4)?.parse().ok()),
+        };
+        Ok(Album {
+            label: title.clone().unwrap_or_default(),
+            fanart: None,
+            thumbnail: self.first("thumb"),
+            art: None,
+            dateadded: None,
+            genre: self.all("genre"),
+            artist: self.all("albumArtistCredits/artist"),
+            artistid: Vec::new(),
+            displayartist: self.first("artistdesc"),
+            musicbrainzalbumartistid: self.non_empty("albumArtistCredits/musicBrainzArtistID"),
+            sortartist: None,
+            title,
+            userrating: self.parse("userrating")?,
+            votes: self.parse("votes")?,
+            year,
+            albumid: 0,
+            albumlabel: self.first("label"),
+            compilation: self.parse("compilation")?,
+            description: self.first("review"),
+            lastplayed: None,
+            mood: self.non_empty("mood"),
+            musicbrainzalbumid: self.first("musicbrainzalbumid"),
+            musicbrainzreleasegroupid: self.first("musicbrainzreleasegroupid"),
+            playcount: None,
+            sourceid: Vec::new(),
+            style: self.all("style"),
+            theme: self.all("theme"),
+            kind: self.first("type"),
+        })
+    }
+
+    /// Parses a duration written as `[hours:]minutes:seconds` into seconds
+    fn duration(&self, path: &str) -> Result<Option<usize>, ExportError> {
+        self.first(path)
+            .map(|text| {
+                text.split(':')
+                    .try_fold(0, |duration, part| {
+                        Some(duration * 60 + part.parse::<usize>().ok()?)
+                    })
+                    .ok_or_else(|| ExportError::InvalidValue {
+                        element: path.to_owned(),
+                        value: text.clone(),
+                    })
+            })
+            .transpose()
+    }
+
+    fn song(&self, album: &Album) -> Result<Song, ExportError> {
+        let title = self.first("title");
+        // The disc number is stored in the upper 16 bits of the position
+        let position: Option<usize> = self.parse("position")?;
+        Ok(Song {
+            label: title.clone().unwrap_or_default(),
+            fanart: None,
+            thumbnail: None,
+            art: None,
+            dateadded: None,
+            genre: Vec::new(),
+            artist: Vec::new(),
+            artistid: Vec::new(),
+            displayartist: None,
+            musicbrainzalbumartistid: album.musicbrainzalbumartistid.clone(),
+            rating: None,
+            sortartist: None,
+            title,
+            userrating: None,
+            votes: None,
+            year: album.year,
+            album: album.title.clone(),
+            albumartist: album.artist.clone(),
+            albumartistid: Vec::new(),
+            albumid: None,
+            comment: None,
+            disc: position.map(|position| position >> 16),
+            displaycomposer: None,
+            displayconductor: None,
+            displaylyricist: None,
+            displayorchestra: None,
+            duration: self.duration("duration")?,
+            file: None,
+            genreid: Vec::new(),
+            lastplayed: None,
+            lyrics: None,
+            mood: Vec::new(),
+            musicbrainzartistid: None,
+            musicbrainztrackid: self.first("musicbrainztrackid"),
+            playcount: None,
+            songid: 0,
+            sourceid: Vec::new(),
+            track: position.map(|position| position & 0xffff),
+        })
+    }
+
+    fn artist(&self) -> Result<Artist, ExportError> {
+        let name = self.first("name").unwrap_or_default();
+        Ok(Artist {
+            label: name.clone(),
+            fanart: self.first("fanart/thumb"),
+            thumbnail: self.first("thumb"),
+            art: None,
+            dateadded: None,
+            genre: self.all("genre"),
+            artist: name,
+            artistid: 0,
+            born: self.first("born"),
+            compilationartist: false,
+            description: self.first("biography"),
+            died: self.first("died"),
+            disambiguation: self.first("disambiguation"),
+            disbanded: self.first("disbanded"),
+            formed: self.first("formed"),
+            gender: self.first("gender"),
+            instrument: self.all("instruments"),
+            isalbumartist: false,
+            mood: self.all("mood"),
+            musicbrainzartistid: self.non_empty("musicBrainzArtistID"),
+            sortname: self.first("sortname"),
+            sourceid: Vec::new(),
+            style: self.all("style"),
+            kind: self.first("type"),
+            yearsactive: self.all("yearsactive"),
+        })
+    }
+}
+
+#[test]
+fn test_music_library() {
+    use crate::types::audio::changes;
+
+    let library = MusicLibrary::parse(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<musicdb>
+    <album>
+        <title>OK Computer</title>
+        <musicbrainzalbumid>b1392450-e666-3926-a536-22c65f834433</musicbrainzalbumid>
+        <artistdesc>Radiohead</artistdesc>
+        <genre>Alternative Rock</genre>
+        <genre>Art Rock</genre>
+        <style>Experimental</style>
+        <compilation>false</compilation>
+        <review>Rock &amp; roll</review>
+        <label>Parlophone</label>
+        <thumb preview="">http://example.com/front.jpg</thumb>
+        <userrating max="10">8</userrating>
+        <releasedate>1997-05-21</releasedate>
+        <albumArtistCredits>
+            <artist>Radiohead</artist>
+            <musicBrainzArtistID>a74b1b7f-71a5-4011-9441-d0b5e4122711</musicBrainzArtistID>
+        </albumArtistCredits>
+        <track>
+            <musicbrainztrackid>2b4b2f3c-0c5c-4b32-8d25-2f4e1b4f5d3a</musicbrainztrackid>
+            <title>Airbag</title>
+            <position>65537</position>
+            <duration>4:44</duration>
+        </track>
+        <track>
+            <title>Paranoid Android</title>
+            <position>65538</position>
+            <duration>6:23</duration>
+        </track>
+    </album>
+    <artist>
+        <name>Radiohead</name>
+        <musicBrainzArtistID>a74b1b7f-71a5-4011-9441-d0b5e4122711</musicBrainzArtistID>
+        <type>Group</type>
+        <formed>1985</formed>
+        <fanart>
+            <thumb>http://example.com/fanart.jpg</thumb>
+        </fanart>
+        <album>
+            <title>OK Computer</title>
+            <year>1997</year>
+        </album>
+    </artist>
+</musicdb>"#,
+    )
+    .unwrap();
+
+    let album = &library.albums[0];
+    assert_eq!(album.title.as_deref(), Some("OK Computer"));
+    assert_eq!(album.genre, vec!["Alternative Rock", "Art Rock"]);
+    assert_eq!(album.artist, vec!["Radiohead"]);
+    assert_eq!(album.description.as_deref(), Some("Rock & roll"));
+    assert_eq!(album.albumlabel.as_deref(), Some("Parlophone"));
+    assert_eq!(album.compilation, Some(false));
+    assert_eq!(album.userrating, Some(8));
+    assert_eq!(album.year, Some(1997));
+
+    let songs = &library.songs;
+    assert_eq!(songs.len(), 2);
+    assert_eq!(songs[0].title.as_deref(), Some("Airbag"));
+    assert_eq!(songs[0].album.as_deref(), Some("OK Computer"));
+    assert_eq!(songs[0].albumartist, vec!["Radiohead"]);
+    assert_eq!((songs[0].disc, songs[0].track), (Some(1), Some(1)));
+    assert_eq!(songs[0].duration, Some(284));
+    assert!(songs[0].musicbrainztrackid.is_some());
+    assert_eq!(songs[1].track, Some(2));
+    assert!(songs[1].musicbrainztrackid.is_none());
+    assert!(songs[0].artist.is_empty() && songs[0].genre.is_empty());
+
+    let mut live = songs[0].clone();
+    live.artist = vec!["Radiohead".to_owned()];
+    live.genre = vec!["Alternative Rock".to_owned()];
+    live.musicbrainzartistid = Some(vec!["a74b1b7f-71a5-4011-9441-d0b5e4122711".to_owned()]);
+    live.playcount = Some(3);
+    assert!(!changes::Song::diff(&songs[0], &live).is_empty());
+    assert!(changes::Song::diff(&songs[0], &live)
+        .exported_only()
+        .is_empty());
+    live.duration = Some(285);
+    assert_eq!(
+        changes::Song::diff(&songs[0], &live).exported_only(),
+        changes::Song::default().duration(285)
+    );
+
+    let artist = &library.artists[0];
+    assert_eq!(artist.artist, "Radiohead");
+    assert_eq!(artist.kind.as_deref(), Some("Group"));
+    assert_eq!(
+        artist.fanart.as_deref(),
+        Some("http://example.com/fanart.jpg")
+    );
+
+    assert!(matches!(
+        MusicLibrary::parse("<musicdb><album><year>soon</year></album></musicdb>"),
+        Err(ExportError::InvalidValue { .. })
+    ));
+}
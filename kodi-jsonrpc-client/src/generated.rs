@@ -0,0 +1,40 @@
+//! Bindings generated at build time for every method and type of the Kodi JSON-RPC
+//! introspection schema shipped with this crate (`schema.json`).
+//!
+//! They follow the schema to the letter: methods live in `methods`, named like their
+//! hand-written counterparts, and types live in `types`, one module per namespace.
+//! Optional parameters and properties are `Option`s.
+//!
+//! The hand-written methods also described by the schema are checked against them by
+//! `test_hand_written_bindings`, which is generated along with them.
+#![allow(clippy::all, rustdoc::bare_urls)]
+
+include!(concat!(env!("OUT_DIR"), "/generated.rs"));
+
+#[test]
+fn test_generated_bindings() {
+    use methods::{AudioLibraryGetSongs, AudioLibraryGetSongsResponse};
+    use types::audio::fields::SongItem;
+    use types::list::Limits;
+
+    let method = AudioLibraryGetSongs {
+        properties: Some(vec![SongItem::Title, SongItem::Albumartist]),
+        limits: Some(Limits {
+            start: Some(0),
+            end: Some(2),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_string(&method).unwrap(),
+        r#"{"properties":["title","albumartist"],"limits":{"end":2,"start":0}}"#
+    );
+
+    let response: AudioLibraryGetSongsResponse = serde_json::from_str(
+        r#"{"limits":{"end":1,"start":0,"total":1},"songs":[{"albumartist":["Foo"],"label":"Bar","songid":42,"title":"Bar"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(response.limits.total, 1);
+    let song = &response.songs.unwrap()[0];
+    assert_eq!((song.songid, song.title.as_deref()), (42, Some("Bar")));
+}
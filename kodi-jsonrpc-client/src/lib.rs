@@ -1,11 +1,26 @@
-use reqwest::{Client, Url};
+use async_trait::async_trait;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
 use tracing::{event, Level};
 
+pub mod batch;
+pub mod capabilities;
+pub mod export;
+pub mod generated;
+pub mod http;
 pub mod methods;
+mod multiplex;
+pub mod notifications;
+pub mod paging;
+pub mod policy;
+pub mod query;
+pub mod remote;
+pub mod tcp;
 pub mod types;
+pub mod websocket;
 
 fn deserialize_opt_usize<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
 where
@@ -61,22 +76,46 @@ pub enum KodiError {
     },
     RequestWriting {
         method: String,
-        error: reqwest::Error,
+        error: BoxError,
     },
     ResponseReading {
         method: String,
-        error: reqwest::Error,
+        error: BoxError,
     },
     ResponseDeserialization {
         method: String,
         payload: String,
         error: serde_json::error::Error,
     },
+    /// Kodi answered with a JSON-RPC error
     Jsonrpc {
         method: String,
-        id: usize,
-        code: i64,
+        /// Missing when Kodi could not read the request, such as for a `ParseError`
+        id: Option<usize>,
+        code: KodiErrorCode,
         message: String,
+        data: Option<Box<KodiErrorData>>,
+    },
+    Unsupported {
+        method: String,
+        /// Version of the JSON-RPC API of the connected Kodi
+        version: (usize, usize, usize),
+        feature: capabilities::Unsupported,
+    },
+    /// No response was received before the timeout of the policy of the client
+    Timeout {
+        method: String,
+        timeout: Duration,
+    },
+    /// The request was not sent since Kodi has been unreachable lately
+    CircuitOpen {
+        method: String,
+        retry_in: Duration,
+    },
+    /// A file could not be downloaded from the Kodi web server
+    Download {
+        path: String,
+        error: BoxError,
     },
 }
 
@@ -87,29 +126,207 @@ impl std::fmt::Display for KodiError {
             Self::RequestWriting { error, .. } => write!(f, "{}", error),
             Self::ResponseReading { error, .. } => write!(f, "{}", error),
             Self::ResponseDeserialization { error, .. } => write!(f, "{}", error),
-            Self::Jsonrpc { message, .. } => write!(f, "{}", message),
+            Self::Jsonrpc {
+                method,
+                message,
+                data,
+                ..
+            } => {
+                write!(f, "{}: {}", method, message)?;
+                match data.as_ref().and_then(|data| data.stack.as_ref()) {
+                    Some(stack) => write!(f, " ({})", stack),
+                    None => Ok(()),
+                }
+            }
+            Self::Unsupported {
+                method,
+                version: (major, minor, patch),
+                feature,
+            } => write!(
+                f,
+                "{}: {} (JSON-RPC API {}.{}.{})",
+                method, feature, major, minor, patch
+            ),
+            Self::Timeout { method, timeout } => {
+                write!(f, "{}: no response after {:?}", method, timeout)
+            }
+            Self::CircuitOpen { method, retry_in } => write!(
+                f,
+                "{}: Kodi is unreachable, retrying in {:?}",
+                method, retry_in
+            ),
+            Self::Download { path, error } => write!(f, "{}: {}", path, error),
         }
     }
 }
 
 impl std::error::Error for KodiError {}
 
+impl KodiError {
+    fn from_transport(method: String, error: KodiTransportError) -> Self {
+        match error {
+            KodiTransportError::Writing(error) => Self::RequestWriting { method, error },
+            KodiTransportError::Reading(error) => Self::ResponseReading { method, error },
+        }
+    }
+}
+
+/// Code of a JSON-RPC error
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[serde(from = "i64")]
+pub enum KodiErrorCode {
+    /// The request is not valid JSON
+    ParseError,
+    /// The request is not a valid JSON-RPC request
+    InvalidRequest,
+    MethodNotFound,
+    /// The parameters do not match the method definition, details being in the error data
+    InvalidParams,
+    InternalError,
+    /// Any other code, such as the ones Kodi uses when it fails to execute a method (-32100)
+    /// or when the client lacks the permission to call it (-32099)
+    Server(i64),
+}
+
+impl KodiErrorCode {
+    pub fn code(self) -> i64 {
+        match self {
+            Self::ParseError => -32700,
+            Self::InvalidRequest => -32600,
+            Self::MethodNotFound => -32601,
+            Self::InvalidParams => -32602,
+            Self::InternalError => -32603,
+            Self::Server(code) => code,
+        }
+    }
+}
+
+impl From<i64> for KodiErrorCode {
+    fn from(code: i64) -> Self {
+        match code {
+            -32700 => Self::ParseError,
+            -32600 => Self::InvalidRequest,
+            -32601 => Self::MethodNotFound,
+            -32602 => Self::InvalidParams,
+            -32603 => Self::InternalError,
+            code => Self::Server(code),
+        }
+    }
+}
+
+/// Details Kodi gives along with a JSON-RPC error
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct KodiErrorData {
+    /// Name of the method as Kodi understood it
+    pub method: Option<String>,
+    pub stack: Option<KodiErrorStack>,
+}
+
+/// Parameter that failed validation, down to the nested property at fault
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize)]
+pub struct KodiErrorStack {
+    pub name: String,
+    /// Expected types, several for union types
+    #[serde(rename = "type", default, deserialize_with = "deserialize_one_or_many")]
+    pub kind: Vec<String>,
+    #[serde(default)]
+    pub message: String,
+    pub property: Option<Box<KodiErrorStack>>,
+}
+
+impl std::fmt::Display for KodiErrorStack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.name, self.message)?;
+        if let Some(property) = &self.property {
+            write!(f, ", {}", property)?;
+        }
+        Ok(())
+    }
+}
+
+fn deserialize_one_or_many<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        One(String),
+        Many(Vec<String>),
+    }
+    match Value::deserialize(deserializer)? {
+        Value::One(value) => Ok(vec![value]),
+        Value::Many(values) => Ok(values),
+    }
+}
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Error returned by a [`KodiTransport`] when a message could not be exchanged with Kodi
+#[derive(Debug)]
+pub enum KodiTransportError {
+    /// The request could not be sent
+    Writing(BoxError),
+    /// The response could not be received
+    Reading(BoxError),
+}
+
+impl std::fmt::Display for KodiTransportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Writing(error) => write!(f, "{}", error),
+            Self::Reading(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for KodiTransportError {}
+
+/// Channel used by [`KodiClient`] to exchange JSON-RPC messages with Kodi
+#[async_trait]
+pub trait KodiTransport: Send + Sync {
+    /// Sends a serialized request and returns the serialized response to the request `id`
+    async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError>;
+}
+
+#[async_trait]
+impl<T: KodiTransport + ?Sized> KodiTransport for Box<T> {
+    async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+        (**self).send(id, body).await
+    }
+}
+
+/// Transport over which Kodi can push notifications, i.e. a persistent connection
+pub trait KodiNotificationTransport: KodiTransport {
+    /// Returns a receiver of the serialized notifications received from now on
+    fn subscribe(&self) -> tokio::sync::broadcast::Receiver<String>;
+}
+
+impl<T: KodiNotificationTransport + ?Sized> KodiNotificationTransport for Box<T> {
+    fn subscribe(&self) -> tokio::sync::broadcast::Receiver<String> {
+        (**self).subscribe()
+    }
+}
+
 pub trait KodiMethod: std::fmt::Debug + Serialize {
     const NAME: &'static str;
+    /// Whether sending the method several times has the same effect as sending it once, so
+    /// that it can be retried when Kodi does not answer
+    const IDEMPOTENT: bool = false;
     type Response: DeserializeOwned;
 }
 
 #[derive(Debug, Serialize)]
-struct KodiRequest<M: KodiMethod> {
+struct KodiRequest<'a, M: KodiMethod> {
     jsonrpc: &'static str,
     #[serde(rename = "method")]
     name: &'static str,
-    params: M,
+    params: &'a M,
     id: usize,
 }
 
-impl<M: KodiMethod> KodiRequest<M> {
-    fn new(method: M, id: usize) -> Self {
+impl<'a, M: KodiMethod> KodiRequest<'a, M> {
+    fn new(method: &'a M, id: usize) -> Self {
         Self {
             jsonrpc: "2.0",
             name: M::NAME,
@@ -118,44 +335,35 @@ impl<M: KodiMethod> KodiRequest<M> {
         }
     }
 
-    async fn send(self, client: &Client, url: Url) -> Result<M::Response, KodiError> {
-        let body =
-            serde_json::to_string(&self).map_err(|error| KodiError::RequestSerialization {
-                method: format!("{:?}", self),
-                error,
-            })?;
-        event!(Level::DEBUG, "-> {body}", body = body);
-        let text = client
-            .post(url)
-            .body(body)
-            .send()
-            .await
-            .map_err(|error| KodiError::RequestWriting {
-                method: format!("{:?}", self),
-                error,
-            })?
-            .text()
-            .await
-            .map_err(|error| KodiError::ResponseReading {
-                method: format!("{:?}", self),
-                error,
-            })?;
-        event!(Level::DEBUG, "<- {text}", text = text);
+    fn to_json(&self) -> Result<String, KodiError> {
+        serde_json::to_string(self).map_err(|error| KodiError::RequestSerialization {
+            method: M::NAME.to_owned(),
+            error,
+        })
+    }
+
+    fn to_value(&self) -> Result<serde_json::Value, KodiError> {
+        serde_json::to_value(self).map_err(|error| KodiError::RequestSerialization {
+            method: M::NAME.to_owned(),
+            error,
+        })
+    }
+
+    /// Extracts the result from the response to this request within a batch, if any
+    fn batch_result(&self, value: Option<serde_json::Value>) -> Result<M::Response, KodiError> {
+        let value = value.ok_or_else(|| KodiError::ResponseReading {
+            method: M::NAME.to_owned(),
+            error: "missing response in batch".into(),
+        })?;
         let resp: KodiResponse<M::Response> =
-            serde_json::from_str(&text).map_err(|error| KodiError::ResponseDeserialization {
-                method: format!("{:?}", self),
-                error,
-                payload: text,
+            serde_json::from_value(value.clone()).map_err(|error| {
+                KodiError::ResponseDeserialization {
+                    method: M::NAME.to_owned(),
+                    error,
+                    payload: value.to_string(),
+                }
             })?;
-        match resp.kind {
-            KodiResponseKind::Result(result) => Ok(result),
-            KodiResponseKind::Error { code, message } => Err(KodiError::Jsonrpc {
-                method: format!("{:?}", self),
-                id: resp.id,
-                code,
-                message,
-            }),
-        }
+        resp.into_result(M::NAME)
     }
 }
 
@@ -164,34 +372,397 @@ enum KodiResponseKind<T> {
     #[serde(rename = "result")]
     Result(T),
     #[serde(rename = "error")]
-    Error { code: i64, message: String },
+    Error {
+        code: KodiErrorCode,
+        message: String,
+        data: Option<Box<KodiErrorData>>,
+    },
 }
 
 #[derive(Debug, Deserialize)]
 struct KodiResponse<T> {
     #[serde(flatten)]
     kind: KodiResponseKind<T>,
-    id: usize,
+    /// `null` when Kodi could not read the request
+    id: Option<usize>,
+}
+
+impl<T> KodiResponse<T> {
+    fn into_result(self, method: &str) -> Result<T, KodiError> {
+        match self.kind {
+            KodiResponseKind::Result(result) => Ok(result),
+            KodiResponseKind::Error {
+                code,
+                message,
+                data,
+            } => Err(KodiError::Jsonrpc {
+                method: method.to_owned(),
+                id: self.id,
+                code,
+                message,
+                data,
+            }),
+        }
+    }
 }
 
-pub struct KodiClient {
-    url: Url,
-    client: Client,
+pub struct KodiClient<T: KodiTransport = http::HttpTransport> {
+    transport: T,
     next_id: AtomicUsize,
+    capabilities: Option<capabilities::KodiCapabilities>,
+    policy: policy::KodiPolicy,
+    breaker: Option<Arc<policy::CircuitBreaker>>,
+}
+
+impl KodiClient<http::HttpTransport> {
+    /// Creates a client sending requests to `url`, authenticated with the credentials it embeds
+    /// if any. Other authentication and TLS settings are set with [`http::HttpTransport::builder`].
+    pub fn new(client: reqwest::Client, url: reqwest::Url) -> Self {
+        Self::with_transport(http::HttpTransport::new(client, url))
+    }
+
+    /// Downloads a file through the Kodi web server, returning a stream of its content.
+    ///
+    /// `path` is any path Kodi can read, including the `image://` URLs of artworks, so that
+    /// files can be retrieved without access to the filesystem of the host running Kodi.
+    pub async fn download(
+        &self,
+        path: &str,
+    ) -> Result<impl futures::Stream<Item = Result<bytes::Bytes, KodiError>> + Unpin, KodiError>
+    {
+        let download_error = |error: BoxError| KodiError::Download {
+            path: path.to_owned(),
+            error,
+        };
+        let prepared = self
+            .send_method(methods::FilesPrepareDownload {
+                path: path.to_owned(),
+            })
+            .await?;
+        let url = prepared
+            .url(self.transport.url())
+            .ok_or_else(|| download_error("unsupported download mode".into()))?;
+        let response = self
+            .transport
+            .get(url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|error| download_error(error.into()))?;
+
+        let path = path.to_owned();
+        Ok(Box::pin(futures::stream::unfold(
+            Some(response),
+            move |response| {
+                let path = path.clone();
+                async move {
+                    let mut response = response?;
+                    match response.chunk().await {
+                        Ok(Some(chunk)) => Some((Ok(chunk), Some(response))),
+                        Ok(None) => None,
+                        Err(error) => Some((
+                            Err(KodiError::Download {
+                                path,
+                                error: error.into(),
+                            }),
+                            None,
+                        )),
+                    }
+                }
+            },
+        )))
+    }
 }
 
-impl KodiClient {
-    pub fn new(client: Client, url: Url) -> Self {
+impl<T: KodiTransport> KodiClient<T> {
+    pub fn with_transport(transport: T) -> Self {
         Self {
-            url,
-            client,
+            transport,
             next_id: AtomicUsize::new(0),
+            capabilities: None,
+            policy: policy::KodiPolicy::default(),
+            breaker: None,
         }
     }
 
+    /// Checks every method against `capabilities` before sending it
+    pub fn with_capabilities(mut self, capabilities: capabilities::KodiCapabilities) -> Self {
+        self.capabilities = Some(capabilities);
+        self
+    }
+
+    pub fn capabilities(&self) -> Option<&capabilities::KodiCapabilities> {
+        self.capabilities.as_ref()
+    }
+
+    /// Sets the timeout and retries of the requests, which are waited for as long as it takes
+    /// and never retried by default
+    pub fn with_policy(mut self, policy: policy::KodiPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Fails requests fast while `breaker` deems Kodi unreachable
+    pub fn with_circuit_breaker(mut self, breaker: Arc<policy::CircuitBreaker>) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    /// Returns the state of the circuit breaker, if any
+    pub fn circuit_state(&self) -> Option<policy::CircuitState> {
+        self.breaker.as_ref().map(|breaker| breaker.state())
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
     pub async fn send_method<M: KodiMethod>(&self, method: M) -> Result<M::Response, KodiError> {
-        KodiRequest::new(method, self.next_id.fetch_add(1, Ordering::Relaxed))
-            .send(&self.client, self.url.clone())
+        self.send_method_ref(&method).await
+    }
+
+    async fn send_method_ref<M: KodiMethod>(&self, method: &M) -> Result<M::Response, KodiError> {
+        if let Some(capabilities) = &self.capabilities {
+            capabilities.check(method)?;
+        }
+        let (_, text) = self
+            .send_body(M::NAME, M::IDEMPOTENT, 1, |id| {
+                KodiRequest::new(method, id).to_json()
+            })
+            .await?;
+        let resp: KodiResponse<M::Response> =
+            serde_json::from_str(&text).map_err(|error| KodiError::ResponseDeserialization {
+                method: M::NAME.to_owned(),
+                error,
+                payload: text,
+            })?;
+        resp.into_result(M::NAME)
+    }
+
+    /// Sends a request through the transport according to the policy and the circuit breaker
+    /// of the client, returning the response along with the first id it has been sent with.
+    ///
+    /// Every attempt takes `ids` new ids and serializes the request with the first of them, so
+    /// that a late response to an attempt is not mistaken for the response to the next one.
+    async fn send_body<F>(
+        &self,
+        method: &str,
+        idempotent: bool,
+        ids: usize,
+        mut serialize: F,
+    ) -> Result<(usize, String), KodiError>
+    where
+        F: FnMut(usize) -> Result<String, KodiError>,
+    {
+        let mut attempt = 0;
+        loop {
+            if let Some(breaker) = &self.breaker {
+                breaker
+                    .acquire()
+                    .map_err(|retry_in| KodiError::CircuitOpen {
+                        method: method.to_owned(),
+                        retry_in,
+                    })?;
+            }
+            let id = self.next_id.fetch_add(ids, Ordering::Relaxed);
+            let sent = self.transport.send(id, serialize(id)?);
+            let transport_error = |error| KodiError::from_transport(method.to_owned(), error);
+            let result = match self.policy.timeout {
+                Some(timeout) => match tokio::time::timeout(timeout, sent).await {
+                    Ok(result) => result.map_err(transport_error),
+                    Err(_) => Err(KodiError::Timeout {
+                        method: method.to_owned(),
+                        timeout,
+                    }),
+                },
+                None => sent.await.map_err(transport_error),
+            };
+            if let Some(breaker) = &self.breaker {
+                breaker.record(result.is_ok());
+            }
+            attempt += 1;
+            match result {
+                Err(error) if idempotent && attempt <= self.policy.retries => {
+                    event!(Level::WARN, "Retrying {} after error: {}", method, error);
+                    tokio::time::sleep(self.policy.backoff(attempt)).await;
+                }
+                result => return result.map(|text| (id, text)),
+            }
+        }
+    }
+
+    /// Returns a stream of all the items listed by `method`, fetched from Kodi in pages of
+    /// `page_size` items, one page at a time as the stream is polled.
+    ///
+    /// The stream ends after the first error.
+    pub fn paginate<'a, M: paging::KodiPagedMethod + 'a>(
+        &'a self,
+        method: M,
+        page_size: usize,
+    ) -> impl futures::Stream<Item = Result<M::Item, KodiError>> + Unpin + 'a {
+        paging::paginate(self, method, page_size)
+    }
+
+    /// Starts a batch of methods to be sent in a single request
+    pub fn batch(&self) -> batch::KodiBatch<'_, T, ()> {
+        batch::KodiBatch::new(self)
+    }
+
+    /// Returns a remote control to drive the Kodi GUI
+    pub fn remote(&self) -> remote::RemoteControl<'_, T> {
+        remote::RemoteControl::new(self)
+    }
+}
+
+impl<T: KodiNotificationTransport> KodiClient<T> {
+    /// Returns a stream of the notifications received from now on, which ends once the
+    /// connection is closed.
+    ///
+    /// Notifications are skipped if the stream is not polled often enough to keep up with them.
+    pub fn notifications(
+        &self,
+    ) -> impl futures::Stream<Item = notifications::KodiNotification> + Unpin {
+        use tokio::sync::broadcast::error::RecvError;
+
+        Box::pin(futures::stream::unfold(
+            self.transport.subscribe(),
+            |mut receiver| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(text) => match text.parse() {
+                            Ok(notification) => return Some((notification, receiver)),
+                            Err(err) => {
+                                event!(Level::WARN, "Ignoring unexpected notification: {}", err)
+                            }
+                        },
+                        Err(RecvError::Lagged(count)) => {
+                            event!(Level::WARN, "Skipped {} notifications", count)
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        ))
+    }
+}
+
+#[tokio::test]
+async fn test_in_memory_transport() {
+    use crate::methods::{ApplicationSetMute, ApplicationSetVolume};
+    use crate::types::global::Toggle;
+
+    /// Answers every request to `method` with a canned result
+    struct InMemoryTransport {
+        method: &'static str,
+        result: &'static str,
+    }
+
+    #[async_trait]
+    impl KodiTransport for InMemoryTransport {
+        async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(request["id"], id);
+            assert_eq!(request["method"], self.method);
+            Ok(format!(
+                r#"{{"id":{},"jsonrpc":"2.0","result":{}}}"#,
+                id, self.result
+            ))
+        }
+    }
+
+    let client = KodiClient::with_transport(InMemoryTransport {
+        method: "Application.SetVolume",
+        result: "42",
+    });
+    assert_eq!(
+        client
+            .send_method(ApplicationSetVolume { volume: 42 })
             .await
+            .unwrap(),
+        42
+    );
+
+    let client: KodiClient<Box<dyn KodiTransport>> =
+        KodiClient::with_transport(Box::new(InMemoryTransport {
+            method: "Application.SetMute",
+            result: "\"OK\"",
+        }));
+    assert!(matches!(
+        client
+            .send_method(ApplicationSetMute {
+                mute: Toggle::Toggle
+            })
+            .await,
+        Err(KodiError::ResponseDeserialization { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_policy() {
+    use crate::methods::{ApplicationSetMute, JSONRPCVersion};
+    use crate::policy::{CircuitBreaker, CircuitState, KodiPolicy};
+    use crate::types::global::Toggle;
+
+    /// Never answers the first `hangs` requests
+    struct HangingTransport {
+        hangs: usize,
+        requests: AtomicUsize,
+        ids: std::sync::Mutex<Vec<usize>>,
+    }
+
+    #[async_trait]
+    impl KodiTransport for HangingTransport {
+        async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            assert_eq!(request["id"], id);
+            self.ids.lock().unwrap().push(id);
+            if self.requests.fetch_add(1, Ordering::Relaxed) < self.hangs {
+                futures::future::pending::<()>().await;
+            }
+            Ok(format!(
+                r#"{{"id":{},"jsonrpc":"2.0","result":{{"version":{{"major":12,"minor":4,"patch":0}}}}}}"#,
+                id
+            ))
+        }
     }
+
+    let policy = KodiPolicy::default()
+        .with_timeout(Duration::from_millis(20))
+        .with_retries(2, Duration::from_millis(1));
+    let client = KodiClient::with_transport(HangingTransport {
+        hangs: 2,
+        requests: AtomicUsize::new(0),
+        ids: Default::default(),
+    })
+    .with_policy(policy);
+    assert!(client.send_method(JSONRPCVersion {}).await.is_ok());
+    assert_eq!(client.transport().requests.load(Ordering::Relaxed), 3);
+    // Every attempt is sent with a new id
+    assert_eq!(*client.transport().ids.lock().unwrap(), vec![0, 1, 2]);
+
+    // Toggling is not idempotent so it is not retried
+    let breaker = Arc::new(CircuitBreaker::new(1, Duration::from_secs(60)));
+    let client = KodiClient::with_transport(HangingTransport {
+        hangs: 1,
+        requests: AtomicUsize::new(0),
+        ids: Default::default(),
+    })
+    .with_policy(policy)
+    .with_circuit_breaker(breaker);
+    let mute = ApplicationSetMute {
+        mute: Toggle::Toggle,
+    };
+    assert!(matches!(
+        client.send_method(mute).await,
+        Err(KodiError::Timeout { .. })
+    ));
+    assert!(matches!(
+        client.circuit_state(),
+        Some(CircuitState::Open { .. })
+    ));
+    assert!(matches!(
+        client.send_method(JSONRPCVersion {}).await,
+        Err(KodiError::CircuitOpen { .. })
+    ));
+    assert_eq!(client.transport().requests.load(Ordering::Relaxed), 1);
 }
@@ -1,16 +1,33 @@
 use clap::Clap;
+use kodi_jsonrpc_client::http::HttpTransport;
 use kodi_jsonrpc_client::methods::*;
+use kodi_jsonrpc_client::types::addon::Content;
+use kodi_jsonrpc_client::types::files::Media;
 use kodi_jsonrpc_client::KodiClient;
 use reqwest::header::{HeaderMap, HeaderValue, CONNECTION};
 use reqwest::Url;
+use std::path::PathBuf;
 use tracing::{event, Level};
 
 #[derive(Clap)]
 #[clap(version = env!("CARGO_PKG_VERSION"), author = env!("CARGO_PKG_AUTHORS"))]
 struct Opts {
-    /// Sets kodi JSON-RPC endpoint
+    /// Sets kodi JSON-RPC endpoint, which may embed the web server credentials
     #[clap(short, long, default_value = "http://127.0.0.1:8080/jsonrpc")]
     kodi: Url,
+
+    /// Reads the kodi web server credentials from a file holding `username:password`, instead
+    /// of the KODI_USERNAME and KODI_PASSWORD environment variables
+    #[clap(long)]
+    credentials_file: Option<PathBuf>,
+
+    /// Trusts the PEM certificate authority at this path
+    #[clap(long)]
+    ca_certificate: Option<PathBuf>,
+
+    /// Accepts self-signed or otherwise invalid certificates
+    #[clap(long)]
+    insecure: bool,
 }
 
 #[tokio::main]
@@ -24,11 +41,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     let mut headers = HeaderMap::new();
     headers.insert(CONNECTION, HeaderValue::from_static("keep-alive"));
-    let client = reqwest::Client::builder()
+    let mut transport = HttpTransport::builder(opts.kodi)
         .default_headers(headers)
-        .http1_title_case_headers()
-        .build()?;
-    let client = KodiClient::new(client, opts.kodi);
+        .credentials_from(opts.credentials_file.as_deref())?
+        .accept_invalid_certificates(opts.insecure);
+    if let Some(path) = opts.ca_certificate {
+        transport = transport.ca_certificate(path);
+    }
+    let client = KodiClient::with_transport(transport.build()?);
 
     let resp = client.send_method(JSONRPCVersion {}).await?;
     event!(Level::INFO, "JSONRPC.Version:\n{:#?}", resp);
@@ -53,6 +73,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         );
     }
 
+    let resp = client
+        .send_method(AddonsGetAddons::plugins(Content::Audio))
+        .await?;
+    event!(Level::INFO, "Addons.GetAddons:\n{:#?}", resp);
+
+    for addon in resp.addons {
+        let resp = match client
+            .send_method(FilesGetDirectory::plugin(&addon.addonid, "", Media::Music))
+            .await
+        {
+            Ok(resp) => resp,
+            Err(err) => {
+                event!(
+                    Level::WARN,
+                    "failed to browse plugin {}; err = {:?}",
+                    addon.addonid,
+                    err
+                );
+                continue;
+            }
+        };
+        event!(
+            Level::INFO,
+            "Files.GetDirectory({:#?}):\n{:#?}",
+            addon.addonid,
+            resp
+        );
+    }
+
     let resp = client.send_method(ApplicationGetProperties::all()).await?;
     event!(Level::INFO, "Application.GetProperties:\n{:#?}", resp);
 
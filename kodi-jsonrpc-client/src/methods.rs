@@ -1,5 +1,13 @@
+/// Defines a method and its parameters. Methods marked `idempotent` only read data, so that
+/// they can be retried when Kodi does not answer.
 macro_rules! define_method {
+    ($( #[$attr:meta] )* idempotent $root:ident . $method:ident { $( $( #[$arg_attr:meta] )* $arg_name:ident : $arg_ty:ty ),* } -> $return_ty:ty) => {
+        define_method!(@define true; $( #[$attr] )* $root . $method { $( $( #[$arg_attr] )* $arg_name : $arg_ty ),* } -> $return_ty);
+    };
     ($( #[$attr:meta] )* $root:ident . $method:ident { $( $( #[$arg_attr:meta] )* $arg_name:ident : $arg_ty:ty ),* } -> $return_ty:ty) => {
+        define_method!(@define false; $( #[$attr] )* $root . $method { $( $( #[$arg_attr] )* $arg_name : $arg_ty ),* } -> $return_ty);
+    };
+    (@define $idempotent:literal; $( #[$attr:meta] )* $root:ident . $method:ident { $( $( #[$arg_attr:meta] )* $arg_name:ident : $arg_ty:ty ),* } -> $return_ty:ty) => {
         paste::paste! {
             #[derive(Debug, serde::Serialize)]
             $( #[$attr] )*
@@ -9,17 +17,118 @@ macro_rules! define_method {
 
             impl $crate::KodiMethod for [<$root $method>] {
                 const NAME: &'static str = std::concat!(std::stringify!($root), ".", std::stringify!($method));
+                const IDEMPOTENT: bool = $idempotent;
                 type Response = $return_ty;
             }
         }
     };
 }
 
+// Addons methods
+
+/// Parameters given to an addon when it is executed
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum AddonsExecuteAddonParams {
+    Map(std::collections::HashMap<String, String>),
+    List(Vec<String>),
+    /// Raw arguments, such as the query string of a `plugin://` URL
+    String(String),
+}
+
+define_method!(
+    #[doc="Executes the given addon with the given parameters (if possible)"]
+    Addons.ExecuteAddon {
+        addonid: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        params: Option<AddonsExecuteAddonParams>,
+        wait: bool
+    } -> String
+);
+
+define_method!(
+    #[doc="Gets the details of a specific addon"]
+    idempotent Addons.GetAddonDetails {
+        addonid: String,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::addon::fields::Addon>
+    } -> AddonsGetAddonDetailsResponse
+);
+
+impl AddonsGetAddonDetails {
+    pub fn all_properties(addonid: String) -> Self {
+        Self {
+            addonid,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AddonsGetAddonDetailsResponse {
+    pub addon: crate::types::addon::details::Addon,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Gets all available addons"]
+    #[derive(Default)]
+    idempotent Addons.GetAddons {
+        #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+        kind: Option<crate::types::addon::Type>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content: Option<crate::types::addon::Content>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        enabled: Option<crate::types::addon::Selection>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::addon::fields::Addon>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        installed: Option<crate::types::addon::Selection>
+    } -> AddonsGetAddonsResponse
+);
+
+impl AddonsGetAddons {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+
+    /// Enabled plugins providing the given content, which can be browsed with
+    /// [`FilesGetDirectory::plugin`]
+    pub fn plugins(content: crate::types::addon::Content) -> Self {
+        Self {
+            kind: Some(crate::types::addon::Type::PluginSource),
+            content: Some(content),
+            enabled: Some(crate::types::addon::Selection::Only(true)),
+            ..Self::all_properties()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AddonsGetAddonsResponse {
+    pub limits: crate::types::list::LimitsReturned,
+    #[serde(default)]
+    pub addons: Vec<crate::types::addon::details::Addon>,
+}
+
+define_method!(
+    #[doc="Enables/Disables a specific addon"]
+    Addons.SetAddonEnabled {
+        addonid: String,
+        enabled: crate::types::global::Toggle
+    } -> String
+);
+
 // Application methods
 
 define_method!(
     #[doc="Retrieves the values of the given properties"]
-    Application.GetProperties {
+    idempotent Application.GetProperties {
         properties: enumset::EnumSet<crate::types::application::property::Name>
     } -> crate::types::application::property::Value
 );
@@ -60,9 +169,53 @@ define_method!(
     } -> String
 );
 
-// AudioLibrary.Export
+/// Where and how the music library is exported
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum AudioLibraryExportOptions {
+    /// Exports the whole library to a single `kodi_music.xml` file in the given directory
+    SingleFile { path: String },
+    /// Exports each album and artist to a separate NFO file, next to its music files
+    SeparateFiles {
+        #[doc = "Whether to export thumbnails and fanart images"]
+        images: bool,
+        #[doc = "Whether to overwrite existing exported files"]
+        overwrite: bool,
+    },
+}
+
+define_method!(
+    #[doc="Exports all items from the audio library"]
+    AudioLibrary.Export {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<AudioLibraryExportOptions>
+    } -> String
+);
+
+define_method!(
+    #[doc="Retrieve details about a specific album"]
+    idempotent AudioLibrary.GetAlbumDetails {
+        #[serde(rename = "albumid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Album>
+    } -> AudioLibraryGetAlbumDetailsResponse
+);
+
+impl AudioLibraryGetAlbumDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
 
-// AudioLibrary.GetAlbumDetails
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioLibraryGetAlbumDetailsResponse {
+    AlbumDetails(crate::types::audio::details::Album),
+}
 
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -80,9 +233,21 @@ pub enum AudioLibraryGetAlbumsFilter {
     Complex(crate::types::list::filter::Albums),
 }
 
+impl From<AudioLibraryGetAlbumsFilterSimple> for AudioLibraryGetAlbumsFilter {
+    fn from(value: AudioLibraryGetAlbumsFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::Albums> for AudioLibraryGetAlbumsFilter {
+    fn from(value: crate::types::list::filter::Albums) -> Self {
+        Self::Complex(value)
+    }
+}
+
 define_method!(
     #[doc="Retrieve all albums from specified artist (and role) or that has songs of the specified genre"]
-    AudioLibrary.GetAlbums {
+    idempotent AudioLibrary.GetAlbums {
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
         properties: enumset::EnumSet<crate::types::audio::fields::Album>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -116,7 +281,30 @@ pub struct AudioLibraryGetAlbumsResponse {
     pub limits: crate::types::list::LimitsReturned,
 }
 
-// AudioLibrary.GetArtistDetails
+define_method!(
+    #[doc="Retrieve details about a specific artist"]
+    idempotent AudioLibrary.GetArtistDetails {
+        #[serde(rename = "artistid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Artist>
+    } -> AudioLibraryGetArtistDetailsResponse
+);
+
+impl AudioLibraryGetArtistDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioLibraryGetArtistDetailsResponse {
+    ArtistDetails(crate::types::audio::details::Artist),
+}
 
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -139,9 +327,21 @@ pub enum AudioLibraryGetArtistsFilter {
     Complex(crate::types::list::filter::Artists),
 }
 
+impl From<AudioLibraryGetArtistsFilterSimple> for AudioLibraryGetArtistsFilter {
+    fn from(value: AudioLibraryGetArtistsFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::Artists> for AudioLibraryGetArtistsFilter {
+    fn from(value: crate::types::list::filter::Artists) -> Self {
+        Self::Complex(value)
+    }
+}
+
 define_method!(
     #[doc="Retrieve all artists. For backward compatibility by default this implicity does not include those that only contribute other roles, however absolutely all artists can be returned using allroles=true"]
-    AudioLibrary.GetArtists {
+    idempotent AudioLibrary.GetArtists {
         #[serde(skip_serializing_if = "Option::is_none")]
         albumartistsonly: Option<bool>,
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
@@ -176,21 +376,214 @@ pub struct AudioLibraryGetArtistsResponse {
     pub limits: crate::types::list::LimitsReturned,
 }
 
-// AudioLibrary.GetGenres
+define_method!(
+    #[doc="Retrieve all genres"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetGenres {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::library::fields::Genre>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetGenresResponse
+);
+
+impl AudioLibraryGetGenres {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct AudioLibraryGetGenresResponse {
+    #[serde(default)]
+    pub genres: Vec<crate::types::library::details::Genre>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieves the values of the music library properties"]
+    idempotent AudioLibrary.GetProperties {
+        properties: enumset::EnumSet<crate::types::audio::property::Name>
+    } -> crate::types::audio::property::Value
+);
+
+impl AudioLibraryGetProperties {
+    /// Requests the properties every Kodi knows about, newer ones being rejected by Kodi 18
+    pub fn all() -> Self {
+        use crate::types::audio::property::Name;
+
+        Self {
+            properties: Name::MissingArtistId | Name::LibraryLastUpdated,
+        }
+    }
+
+    /// Requests every property the connected Kodi knows about
+    pub fn supported(capabilities: &crate::capabilities::KodiCapabilities) -> Self {
+        use crate::types::audio::property::Name;
+
+        Self {
+            properties: enumset::EnumSet::<Name>::all()
+                .iter()
+                .filter(|name| match serde_json::to_value(name) {
+                    Ok(serde_json::Value::String(name)) => {
+                        capabilities.has_property("Audio.Property.Name", &name)
+                    }
+                    _ => false,
+                })
+                .collect(),
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieve recently added albums"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetRecentlyAddedAlbums {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Album>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetAlbumsResponse
+);
+
+impl AudioLibraryGetRecentlyAddedAlbums {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieve recently added songs"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetRecentlyAddedSongs {
+        #[doc="The amount of recently added albums from which to return the songs"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        albumlimit: Option<usize>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Song>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetSongsResponse
+);
+
+impl AudioLibraryGetRecentlyAddedSongs {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieve recently played albums"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetRecentlyPlayedAlbums {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Album>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetAlbumsResponse
+);
+
+impl AudioLibraryGetRecentlyPlayedAlbums {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieve recently played songs"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetRecentlyPlayedSongs {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Song>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetSongsResponse
+);
 
-// AudioLibrary.GetProperties
+impl AudioLibraryGetRecentlyPlayedSongs {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
 
-// AudioLibrary.GetRecentlyAddedAlbums
+define_method!(
+    #[doc="Retrieve all contributor roles"]
+    #[derive(Default)]
+    idempotent AudioLibrary.GetRoles {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Role>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> AudioLibraryGetRolesResponse
+);
 
-// AudioLibrary.GetRecentlyAddedSongs
+impl AudioLibraryGetRoles {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
 
-// AudioLibrary.GetRecentlyPlayedAlbums
+#[derive(Debug, serde::Deserialize)]
+pub struct AudioLibraryGetRolesResponse {
+    #[serde(default)]
+    pub roles: Vec<crate::types::audio::details::Role>,
+    pub limits: crate::types::list::LimitsReturned,
+}
 
-// AudioLibrary.GetRecentlyPlayedSongs
+define_method!(
+    #[doc="Retrieve details about a specific song"]
+    idempotent AudioLibrary.GetSongDetails {
+        #[serde(rename = "songid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::audio::fields::Song>
+    } -> AudioLibraryGetSongDetailsResponse
+);
 
-// AudioLibrary.GetRoles
+impl AudioLibraryGetSongDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
 
-// AudioLibrary.GetSongDetails
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioLibraryGetSongDetailsResponse {
+    SongDetails(crate::types::audio::details::Song),
+}
 
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
@@ -224,7 +617,7 @@ impl From<crate::types::list::filter::Songs> for AudioLibraryGetSongsFilter {
 
 define_method!(
     #[doc="Retrieve all songs from specified album, artist or genre"]
-    AudioLibrary.GetSongs {
+    idempotent AudioLibrary.GetSongs {
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
         properties: enumset::EnumSet<crate::types::audio::fields::Song>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -263,7 +656,7 @@ pub struct AudioLibraryGetSongsResponse {
 define_method!(
     #[doc="Get all music sources, including unique ID"]
     #[derive(Default)]
-    AudioLibrary.GetSources {
+    idempotent AudioLibrary.GetSources {
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
         properties: enumset::EnumSet<crate::types::library::fields::Source>,
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -290,18 +683,111 @@ define_method!(
     } -> String
 );
 
-// AudioLibrary.SetAlbumDetails
-
-// AudioLibrary.SetArtistDetails
-
-// AudioLibrary.SetSongDetails
-
-// Files methods
-
 define_method!(
-    #[doc="Get the directories and files in the given directory"]
-    #[derive(Default)]
-    Files.GetDirectory {
+    #[doc="Update the given album with the given details. Only the details set in the change set are changed."]
+    AudioLibrary.SetAlbumDetails {
+        #[serde(rename = "albumid")]
+        id: usize,
+        #[serde(flatten)]
+        changes: crate::types::audio::changes::Album
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given artist with the given details. Only the details set in the change set are changed."]
+    AudioLibrary.SetArtistDetails {
+        #[serde(rename = "artistid")]
+        id: usize,
+        #[serde(flatten)]
+        changes: crate::types::audio::changes::Artist
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given song with the given details. Only the details set in the change set are changed."]
+    AudioLibrary.SetSongDetails {
+        #[serde(rename = "songid")]
+        id: usize,
+        #[serde(flatten)]
+        changes: crate::types::audio::changes::Song
+    } -> String
+);
+
+// Favourites methods
+
+define_method!(
+    #[doc="Add a favourite with the given details"]
+    #[derive(Default)]
+    Favourites.AddFavourite {
+        title: String,
+        #[serde(rename = "type")]
+        kind: crate::types::favourite::Type,
+        /// Required for media, script and androidapp favourites
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        /// Required for window favourites
+        #[serde(skip_serializing_if = "Option::is_none")]
+        window: Option<crate::types::gui::Window>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        windowparameter: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumbnail: Option<String>
+    } -> String
+);
+
+impl FavouritesAddFavourite {
+    pub fn media(title: String, path: String) -> Self {
+        Self {
+            title,
+            kind: crate::types::favourite::Type::Media,
+            path: Some(path),
+            ..Default::default()
+        }
+    }
+
+    pub fn window(title: String, window: crate::types::gui::Window) -> Self {
+        Self {
+            title,
+            kind: crate::types::favourite::Type::Window,
+            window: Some(window),
+            ..Default::default()
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieve all favourites"]
+    #[derive(Default)]
+    idempotent Favourites.GetFavourites {
+        #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+        kind: Option<crate::types::favourite::Type>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::favourite::fields::Favourite>
+    } -> FavouritesGetFavouritesResponse
+);
+
+impl FavouritesGetFavourites {
+    pub fn all_properties() -> Self {
+        Self {
+            kind: None,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct FavouritesGetFavouritesResponse {
+    pub limits: crate::types::list::LimitsReturned,
+    #[serde(default)]
+    pub favourites: Vec<crate::types::favourite::details::Favourite>,
+}
+
+// Files methods
+
+define_method!(
+    #[doc="Get the directories and files in the given directory"]
+    #[derive(Default)]
+    idempotent Files.GetDirectory {
         directory: String,
         media: crate::types::files::Media,
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
@@ -323,6 +809,15 @@ impl FilesGetDirectory {
             sort: None,
         }
     }
+
+    /// Browses a directory of a plugin, `path` being relative to the root of the plugin and
+    /// possibly holding the query string the plugin expects, such as `?mode=artists`
+    pub fn plugin(addonid: &str, path: &str, media: crate::types::files::Media) -> Self {
+        Self::all_properties(
+            format!("plugin://{}/{}", addonid, path.trim_start_matches('/')),
+            media,
+        )
+    }
 }
 
 #[derive(Debug, serde::Deserialize)]
@@ -335,7 +830,7 @@ pub struct FilesGetDirectoryResponse {
 define_method!(
     #[doc="Get details for a specific file"]
     #[derive(Default)]
-    Files.GetFileDetails {
+    idempotent Files.GetFileDetails {
         file: String,
         media: crate::types::files::Media,
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
@@ -361,329 +856,540 @@ pub enum FilesGetFileDetailsResponse {
 
 // Files.GetSources
 
-// Files.PrepareDownload
+define_method!(
+    #[doc="Provides a way to download a given file (e.g. providing an URL to the real file location)"]
+    idempotent Files.PrepareDownload {
+        path: String
+    } -> FilesPrepareDownloadResponse
+);
 
-// Files.SetFileDetails
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilesPrepareDownloadProtocol {
+    Http,
+}
 
-// JSONRPC methods
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FilesPrepareDownloadMode {
+    /// The file is downloaded from the URL given in the details
+    Redirect,
+    /// The file is downloaded using `Files.Download`
+    Direct,
+}
 
-#[derive(Debug, serde::Deserialize)]
-pub enum JSONRPCVersionResponse {
-    #[serde(rename = "version")]
-    Version {
-        major: usize,
-        minor: usize,
-        patch: usize,
-    },
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct FilesPrepareDownloadDetails {
+    /// Path of the file on the Kodi web server, such as `vfs/%2fmusic%2fsong.flac`
+    pub path: String,
 }
 
-define_method!(
-    #[doc="Retrieve the JSON-RPC protocol version."]
-    JSONRPC.Version {} -> JSONRPCVersionResponse
-);
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct FilesPrepareDownloadResponse {
+    pub protocol: FilesPrepareDownloadProtocol,
+    pub mode: FilesPrepareDownloadMode,
+    pub details: FilesPrepareDownloadDetails,
+}
 
-// Player methods
+impl FilesPrepareDownloadResponse {
+    /// URL to download the file from, relative to the JSON-RPC endpoint of the Kodi web server
+    pub fn url(&self, endpoint: &reqwest::Url) -> Option<reqwest::Url> {
+        match (self.protocol, self.mode) {
+            (FilesPrepareDownloadProtocol::Http, FilesPrepareDownloadMode::Redirect) => {
+                endpoint.join(&self.details.path).ok()
+            }
+            _ => None,
+        }
+    }
+}
 
 define_method!(
-    #[doc="Returns all active players"]
-    Player.GetActivePlayers {} -> Vec<crate::types::player::ActivePlayer>
+    #[doc="Update the given specific file with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    Files.SetFileDetails {
+        file: String,
+        #[doc="Media type of the file, only video is currently supported by Kodi"]
+        media: crate::types::files::Media,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        playcount: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lastplayed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        resume: Option<crate::types::video::Resume>
+    } -> String
 );
 
+// GUI methods
+
 define_method!(
-    #[doc="Retrieves the currently played item"]
-    Player.GetItem {
-        #[serde(rename = "playerid")]
-        id: u8,
-        properties: enumset::EnumSet<crate::types::list::fields::All>
-    } -> PlayerGetItemResponse
+    #[doc="Activates the given window"]
+    GUI.ActivateWindow {
+        window: crate::types::gui::Window,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        parameters: Vec<String>
+    } -> String
 );
 
-impl PlayerGetItem {
-    pub fn all_properties(id: u8) -> Self {
+impl GUIActivateWindow {
+    pub fn new(window: crate::types::gui::Window) -> Self {
         Self {
-            id,
-            properties: enumset::EnumSet::all(),
+            window,
+            parameters: Vec::new(),
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum PlayerGetItemResponse {
-    Item(crate::types::list::item::All),
-}
-
-// Player.GetPlayers
-
 define_method!(
     #[doc="Retrieves the values of the given properties"]
-    Player.GetProperties {
-        #[serde(rename = "playerid")]
-        id: u8,
-        properties: enumset::EnumSet<crate::types::player::property::Name>
-    } -> crate::types::player::property::Value
+    idempotent GUI.GetProperties {
+        properties: enumset::EnumSet<crate::types::gui::property::Name>
+    } -> crate::types::gui::property::Value
 );
 
-impl PlayerGetProperties {
-    pub fn all(id: u8) -> Self {
+impl GUIGetProperties {
+    pub fn all() -> Self {
         Self {
-            id,
             properties: enumset::EnumSet::all(),
         }
     }
 }
 
-// Player.GetViewMode
-
 define_method!(
-    #[doc="Go to previous/next/specific item in the playlist"]
-    Player.GoTo {
-        #[serde(rename = "playerid")]
-        id: u8,
-        to: crate::types::player::GoTo
-    } -> String
+    #[doc="Returns the supported stereoscopic modes of the GUI"]
+    idempotent GUI.GetStereoscopicModes {} -> GUIGetStereoscopicModesResponse
 );
 
-// Player.Move
-
-#[derive(Debug, serde::Serialize)]
-#[serde(untagged)]
-pub enum PlayerOpenItem {
-    PlaylistAt {
-        #[serde(rename = "playlistid")]
-        id: usize,
-        position: usize,
-    },
-    // TODO: support other open mode
+#[derive(Debug, serde::Deserialize)]
+pub struct GUIGetStereoscopicModesResponse {
+    #[serde(default)]
+    pub stereoscopicmodes: Vec<crate::types::gui::stereoscopy::Mode>,
 }
 
-#[derive(Debug, Default, serde::Serialize)]
-pub struct PlayerOpenOptions {
-    #[serde(rename = "playername")]
-    name: Option<String>,
-    repeat: Option<bool>,
-    // TODO: support other resume mode
-    resume: bool,
-    shuffled: Option<bool>,
-}
+define_method!(
+    #[doc="Toggle fullscreen/GUI"]
+    GUI.SetFullscreen {
+        fullscreen: crate::types::global::Toggle
+    } -> bool
+);
 
 define_method!(
-    #[doc="Start playback of either the playlist with the given ID, a slideshow with the pictures from the given directory or a single file or an item from the database."]
-    Player.Open {
-        item: PlayerOpenItem,
-        options: PlayerOpenOptions
+    #[doc="Sets the stereoscopic mode of the GUI to the given mode"]
+    GUI.SetStereoscopicMode {
+        mode: crate::types::gui::stereoscopy::SetMode
     } -> String
 );
 
 define_method!(
-    #[doc="Pauses or unpause playback and returns the new state"]
-    Player.PlayPause {
-        #[serde(rename = "playerid")]
-        id: u8,
-        play: crate::types::global::Toggle
-    } -> crate::types::player::Speed
+    #[doc="Shows a GUI notification"]
+    GUI.ShowNotification {
+        title: String,
+        message: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        image: Option<crate::types::gui::NotificationImage>,
+        #[doc="The time in milliseconds the notification will be visible, at least 1500"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        displaytime: Option<usize>
+    } -> String
 );
 
-impl PlayerPlayPause {
-    pub fn new(id: u8) -> Self {
+impl GUIShowNotification {
+    pub fn new(title: String, message: String) -> Self {
         Self {
-            id,
-            play: Default::default(),
+            title,
+            message,
+            image: None,
+            displaytime: None,
         }
     }
 }
 
-// Player.Rotate
-
-#[derive(Debug, serde::Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum PlayerSeekStep {
-    SmallForward,
-    SmallBackward,
-    BigForward,
-    BigBackward,
-}
-
-#[derive(Debug, serde::Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum PlayerSeekMode {
-    Percentage(f64),
-    Time(crate::types::global::Time),
-    Step(PlayerSeekStep),
-    Seconds(isize),
-}
+// Input methods
 
 define_method!(
-    #[doc="Seek through the playing item"]
-    Player.Seek {
-        #[serde(rename = "playerid")]
-        id: u8,
-        value: PlayerSeekMode
-    } -> PlayerSeekResponse
+    #[doc="Goes back in GUI"]
+    Input.Back {} -> String
 );
 
-#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
-pub struct PlayerSeekResponse {
-    pub percentage: f64,
-    pub time: crate::types::global::Time,
-    pub totaltime: crate::types::global::Time,
-}
-
-// Player.SetAudioStream
-
 define_method!(
-    #[doc="Turn partymode on or off"]
-    Player.SetPartymode {
-        #[serde(rename = "playerid")]
-        id: u8,
-        partymode: crate::types::global::Toggle
-    } -> String
+    #[doc="Shows the context menu"]
+    Input.ContextMenu {} -> String
 );
 
 define_method!(
-    #[doc="Set the repeat mode of the player"]
-    Player.SetRepeat {
-        #[serde(rename = "playerid")]
-        id: u8,
-        // TODO: handle `cycle` mode
-        repeat: crate::types::player::Repeat
-    } -> String
+    #[doc="Navigate down in GUI"]
+    Input.Down {} -> String
 );
 
 define_method!(
-    #[doc="Shuffle/Unshuffle items in the player"]
-    Player.SetShuffle {
-        #[serde(rename = "playerid")]
-        id: u8,
-        shuffle: crate::types::global::Toggle
+    #[doc="Execute a specific action"]
+    Input.ExecuteAction {
+        action: crate::types::input::Action
     } -> String
 );
 
-#[derive(
-    Clone, Copy, Debug, PartialEq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr,
-)]
-#[repr(i8)]
-pub enum PlayerSpeedValue {
-    Backward32 = -32,
-    Backward16 = -16,
-    Backward8 = -8,
-    Backward4 = -4,
-    Backward2 = -2,
-    Paused = 0,
-    Normal = 1,
-    Forward2 = 2,
-    Forward4 = 4,
-    Forward8 = 8,
-    Forward16 = 16,
-    Forward32 = 32,
-}
+define_method!(
+    #[doc="Goes to home window in GUI"]
+    Input.Home {} -> String
+);
 
-#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
-#[serde(untagged)]
-pub enum PlayerSpeed {
-    Value(PlayerSpeedValue),
-    IncDec(crate::types::global::IncrementDecrement),
-}
+define_method!(
+    #[doc="Shows the information dialog"]
+    Input.Info {} -> String
+);
 
 define_method!(
-    #[doc="Set the speed of the current playback"]
-    Player.SetSpeed {
-        #[serde(rename = "playerid")]
-        id: u8,
-        speed: PlayerSpeed
-    } -> crate::types::player::Speed
+    #[doc="Navigate left in GUI"]
+    Input.Left {} -> String
 );
 
-// Player.SetSubtitle
+define_method!(
+    #[doc="Navigate right in GUI"]
+    Input.Right {} -> String
+);
 
-// Player.SetVideoStream
+define_method!(
+    #[doc="Select current item in GUI"]
+    Input.Select {} -> String
+);
 
-// Player.SetViewMode
+define_method!(
+    #[doc="Send a generic (unicode) text"]
+    Input.SendText {
+        #[doc="Unicode text"]
+        text: String,
+        #[doc="Whether this is the whole input or not (closes an open input dialog if true)"]
+        done: bool
+    } -> String
+);
 
 define_method!(
-    #[doc="Stops playback"]
-    Player.Stop {
-        #[serde(rename = "playerid")]
-        id: u8
-    } -> String
+    #[doc="Show codec information of the playing item"]
+    Input.ShowCodec {} -> String
 );
 
-impl PlayerStop {
-    pub fn new(id: u8) -> Self {
-        Self { id }
+define_method!(
+    #[doc="Show the on-screen display for the current player"]
+    Input.ShowOSD {} -> String
+);
+
+define_method!(
+    #[doc="Show player process information of the playing item, like video decoder, pixel format, pvr signal strength, ..."]
+    Input.ShowPlayerProcessInfo {} -> String
+);
+
+define_method!(
+    #[doc="Navigate up in GUI"]
+    Input.Up {} -> String
+);
+
+// JSONRPC methods
+
+#[derive(Debug, serde::Serialize)]
+pub struct JSONRPCIntrospectFilter {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub kind: JSONRPCIntrospectFilterType,
+    pub getreferences: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JSONRPCIntrospectFilterType {
+    Method,
+    Namespace,
+    Type,
+    Notification,
+}
+
+define_method!(
+    #[doc="Enumerates all actions and descriptions"]
+    idempotent JSONRPC.Introspect {
+        getdescriptions: bool,
+        getmetadata: bool,
+        filterbytransport: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<JSONRPCIntrospectFilter>
+    } -> JSONRPCIntrospectResponse
+);
+
+impl JSONRPCIntrospect {
+    /// Introspects every method, type and notification available over the current transport,
+    /// along with the permission each method requires but without their descriptions
+    pub fn all() -> Self {
+        Self {
+            getdescriptions: false,
+            getmetadata: true,
+            filterbytransport: true,
+            filter: None,
+        }
     }
 }
 
-// Player.Zoom
+/// Parameter of a method, described by a JSON schema
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct JSONRPCIntrospectParam {
+    pub name: String,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(flatten)]
+    pub schema: serde_json::Map<String, serde_json::Value>,
+}
 
-// Playlist methods
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct JSONRPCIntrospectMethod {
+    pub description: Option<String>,
+    #[serde(default)]
+    pub params: Vec<JSONRPCIntrospectParam>,
+    #[serde(default)]
+    pub returns: serde_json::Value,
+    /// Permission required to call the method, only given when introspecting with metadata
+    pub permission: Option<String>,
+}
+
+/// Methods, notifications and types are indexed by their name, types being JSON schemas
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct JSONRPCIntrospectResponse {
+    pub description: Option<String>,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub methods: std::collections::HashMap<String, JSONRPCIntrospectMethod>,
+    #[serde(default)]
+    pub notifications: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub types: std::collections::HashMap<String, serde_json::Value>,
+}
 
 define_method!(
-    #[doc="Add item(s) to playlist"]
-    Playlist.Add {
-        #[serde(rename = "playlistid")]
-        id: u8,
-        item: Vec<crate::types::playlist::Item>
+    #[doc="Retrieve the clients permissions"]
+    idempotent JSONRPC.Permission {} -> JSONRPCPermissionResponse
+);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct JSONRPCPermissionResponse {
+    pub controlgui: bool,
+    pub controlnotify: bool,
+    pub controlplayback: bool,
+    pub controlpower: bool,
+    pub controlpvr: bool,
+    pub controlsystem: bool,
+    pub executeaddon: bool,
+    pub manageaddon: bool,
+    pub navigate: bool,
+    pub readdata: bool,
+    pub removedata: bool,
+    pub updatedata: bool,
+    pub writefile: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub enum JSONRPCVersionResponse {
+    #[serde(rename = "version")]
+    Version {
+        major: usize,
+        minor: usize,
+        patch: usize,
+    },
+}
+
+define_method!(
+    #[doc="Retrieve the JSON-RPC protocol version."]
+    idempotent JSONRPC.Version {} -> JSONRPCVersionResponse
+);
+
+// PVR methods
+
+define_method!(
+    #[doc="Adds a timer to record the given show one times or a timer rule to record all showings of the given show or adds a reminder timer or reminder timer rule"]
+    PVR.AddTimer {
+        #[doc="The broadcast id of the item to record"]
+        broadcastid: usize,
+        #[doc="Controls whether to create a timer rule or a onetime timer"]
+        timerrule: bool
     } -> String
 );
 
 define_method!(
-    #[doc="Clear playlist"]
-    Playlist.Clear {
-        #[serde(rename = "playlistid")]
-        id: u8
+    #[doc="Deletes a onetime timer or a timer rule"]
+    PVR.DeleteTimer {
+        #[doc="The id of the onetime timer or timer rule to delete"]
+        #[serde(rename = "timerid")]
+        id: usize
     } -> String
 );
 
 define_method!(
-    #[doc="Playlist.GetItems"]
-    Playlist.GetItems {
-        #[serde(rename = "playlistid")]
-        id: u8,
+    #[doc="Retrieves the details of a specific broadcast"]
+    idempotent PVR.GetBroadcastDetails {
+        #[serde(rename = "broadcastid")]
+        id: usize,
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
-        properties: enumset::EnumSet<crate::types::list::fields::All>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        limits: Option<crate::types::list::Limits>,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        sort: Option<crate::types::list::Sort>
-    } -> PlaylistGetItemsResponse
+        properties: enumset::EnumSet<crate::types::pvr::fields::Broadcast>
+    } -> PVRGetBroadcastDetailsResponse
 );
 
-impl PlaylistGetItems {
-    pub fn all_properties(id: u8) -> Self {
+impl PVRGetBroadcastDetails {
+    pub fn all_properties(id: usize) -> Self {
         Self {
             id,
             properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PVRGetBroadcastDetailsResponse {
+    BroadcastDetails(crate::types::pvr::details::Broadcast),
+}
+
+define_method!(
+    #[doc="Retrieves the program of a specific channel"]
+    idempotent PVR.GetBroadcasts {
+        channelid: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Broadcast>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>
+    } -> PVRGetBroadcastsResponse
+);
+
+impl PVRGetBroadcasts {
+    pub fn all_properties(channelid: usize) -> Self {
+        Self {
+            channelid,
+            properties: enumset::EnumSet::all(),
             limits: None,
-            sort: None,
         }
     }
 }
 
-#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
-pub struct PlaylistGetItemsResponse {
+#[derive(Debug, serde::Deserialize)]
+pub struct PVRGetBroadcastsResponse {
     #[serde(default)]
-    pub items: Vec<crate::types::list::item::All>,
+    pub broadcasts: Vec<crate::types::pvr::details::Broadcast>,
     pub limits: crate::types::list::LimitsReturned,
 }
 
 define_method!(
-    #[doc="Returns all existing playlists"]
-    Playlist.GetPlaylists {} -> Vec<crate::types::playlist::Playlist>
+    #[doc="Retrieves the details of a specific channel"]
+    idempotent PVR.GetChannelDetails {
+        #[serde(rename = "channelid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Channel>
+    } -> PVRGetChannelDetailsResponse
+);
+
+impl PVRGetChannelDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PVRGetChannelDetailsResponse {
+    ChannelDetails(crate::types::pvr::details::Channel),
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct PVRGetChannelGroupDetailsChannels {
+    #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+    pub properties: enumset::EnumSet<crate::types::pvr::fields::Channel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<crate::types::list::Limits>,
+}
+
+define_method!(
+    #[doc="Retrieves the details of a specific channel group"]
+    idempotent PVR.GetChannelGroupDetails {
+        #[serde(rename = "channelgroupid")]
+        id: crate::types::pvr::ChannelGroupId,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        channels: Option<PVRGetChannelGroupDetailsChannels>
+    } -> PVRGetChannelGroupDetailsResponse
 );
 
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PVRGetChannelGroupDetailsResponse {
+    ChannelGroupDetails(crate::types::pvr::details::ChannelGroupExtended),
+}
+
+define_method!(
+    #[doc="Retrieves the channel groups for the specified type"]
+    idempotent PVR.GetChannelGroups {
+        channeltype: crate::types::pvr::ChannelType,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>
+    } -> PVRGetChannelGroupsResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PVRGetChannelGroupsResponse {
+    #[serde(default)]
+    pub channelgroups: Vec<crate::types::pvr::details::ChannelGroup>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieves the channel list"]
+    idempotent PVR.GetChannels {
+        channelgroupid: crate::types::pvr::ChannelGroupId,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Channel>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>
+    } -> PVRGetChannelsResponse
+);
+
+impl PVRGetChannels {
+    pub fn all_properties(channelgroupid: crate::types::pvr::ChannelGroupId) -> Self {
+        Self {
+            channelgroupid,
+            properties: enumset::EnumSet::all(),
+            limits: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PVRGetChannelsResponse {
+    #[serde(default)]
+    pub channels: Vec<crate::types::pvr::details::Channel>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
 define_method!(
     #[doc="Retrieves the values of the given properties"]
-    Playlist.GetProperties {
-        #[serde(rename = "playlistid")]
-        id: u8,
+    idempotent PVR.GetProperties {
+        properties: enumset::EnumSet<crate::types::pvr::property::Name>
+    } -> crate::types::pvr::property::Value
+);
+
+impl PVRGetProperties {
+    pub fn all() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+define_method!(
+    #[doc="Retrieves the details of a specific recording"]
+    idempotent PVR.GetRecordingDetails {
+        #[serde(rename = "recordingid")]
+        id: usize,
         #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
-        properties: enumset::EnumSet<crate::types::playlist::property::Name>
-    } -> crate::types::playlist::property::Value
+        properties: enumset::EnumSet<crate::types::pvr::fields::Recording>
+    } -> PVRGetRecordingDetailsResponse
 );
 
-impl PlaylistGetProperties {
-    pub fn all(id: u8) -> Self {
+impl PVRGetRecordingDetails {
+    pub fn all_properties(id: usize) -> Self {
         Self {
             id,
             properties: enumset::EnumSet::all(),
@@ -691,31 +1397,2301 @@ impl PlaylistGetProperties {
     }
 }
 
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PVRGetRecordingDetailsResponse {
+    RecordingDetails(crate::types::pvr::details::Recording),
+}
+
 define_method!(
-    #[doc="Insert item(s) into playlist. Does not work for picture playlists (aka slideshows)."]
-    Playlist.Insert {
-        #[serde(rename = "playlistid")]
-        id: u8,
-        position: usize,
-        item: Vec<crate::types::playlist::Item>
+    #[doc="Retrieves the recordings"]
+    #[derive(Default)]
+    idempotent PVR.GetRecordings {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Recording>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>
+    } -> PVRGetRecordingsResponse
+);
+
+impl PVRGetRecordings {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            limits: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PVRGetRecordingsResponse {
+    #[serde(default)]
+    pub recordings: Vec<crate::types::pvr::details::Recording>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieves the details of a specific timer"]
+    idempotent PVR.GetTimerDetails {
+        #[serde(rename = "timerid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Timer>
+    } -> PVRGetTimerDetailsResponse
+);
+
+impl PVRGetTimerDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PVRGetTimerDetailsResponse {
+    TimerDetails(crate::types::pvr::details::Timer),
+}
+
+define_method!(
+    #[doc="Retrieves the timers"]
+    #[derive(Default)]
+    idempotent PVR.GetTimers {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::pvr::fields::Timer>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>
+    } -> PVRGetTimersResponse
+);
+
+impl PVRGetTimers {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            limits: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PVRGetTimersResponse {
+    #[serde(default)]
+    pub timers: Vec<crate::types::pvr::details::Timer>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Toggle recording of a channel"]
+    #[derive(Default)]
+    PVR.Record {
+        record: crate::types::global::Toggle,
+        channel: crate::types::pvr::RecordChannel
     } -> String
 );
 
 define_method!(
-    #[doc="Remove item from playlist. Does not work for picture playlists (aka slideshows)."]
-    Playlist.Remove {
-        #[serde(rename = "playlistid")]
-        id: u8,
-        position: usize
+    #[doc="Starts a channel scan"]
+    #[derive(Default)]
+    PVR.Scan {
+        #[doc="PVR client to scan with, required by recent versions of Kodi"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        clientid: Option<usize>
     } -> String
 );
 
 define_method!(
-    #[doc="Swap items in the playlist. Does not work for picture playlists (aka slideshows)."]
-    Playlist.Swap {
-        #[serde(rename = "playlistid")]
-        id: u8,
-        position1: usize,
-        position2: usize
+    #[doc="Creates or deletes a onetime timer or timer rule for a given show. If it exists, it will be deleted. If it does not exist, it will be created"]
+    PVR.ToggleTimer {
+        #[doc="The broadcast id of the item to toggle a onetime timer or time rule for"]
+        broadcastid: usize,
+        #[doc="Controls whether to create / delete a timer rule or a onetime timer"]
+        timerrule: bool
     } -> String
 );
+
+// Player methods
+
+define_method!(
+    #[doc="Returns all active players"]
+    idempotent Player.GetActivePlayers {} -> Vec<crate::types::player::ActivePlayer>
+);
+
+define_method!(
+    #[doc="Retrieves the currently played item"]
+    idempotent Player.GetItem {
+        #[serde(rename = "playerid")]
+        id: u8,
+        properties: enumset::EnumSet<crate::types::list::fields::All>
+    } -> PlayerGetItemResponse
+);
+
+impl PlayerGetItem {
+    pub fn all_properties(id: u8) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerGetItemResponse {
+    Item(crate::types::list::item::All),
+}
+
+// Player.GetPlayers
+
+define_method!(
+    #[doc="Retrieves the values of the given properties"]
+    idempotent Player.GetProperties {
+        #[serde(rename = "playerid")]
+        id: u8,
+        properties: enumset::EnumSet<crate::types::player::property::Name>
+    } -> crate::types::player::property::Value
+);
+
+impl PlayerGetProperties {
+    pub fn all(id: u8) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+define_method!(
+    #[doc="Get view mode of video player"]
+    idempotent Player.GetViewMode {} -> PlayerGetViewModeResponse
+);
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct PlayerGetViewModeResponse {
+    pub viewmode: crate::types::player::ViewMode,
+    pub zoom: f64,
+    pub pixelratio: f64,
+    pub verticalshift: f64,
+    pub nonlinearstretch: bool,
+}
+
+define_method!(
+    #[doc="Go to previous/next/specific item in the playlist"]
+    Player.GoTo {
+        #[serde(rename = "playerid")]
+        id: u8,
+        to: crate::types::player::GoTo
+    } -> String
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerMoveDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+define_method!(
+    #[doc="If picture is zoomed move viewport left/right/up/down otherwise skip previous/next"]
+    Player.Move {
+        #[serde(rename = "playerid")]
+        id: u8,
+        direction: PlayerMoveDirection
+    } -> String
+);
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerOpenItem {
+    PlaylistAt {
+        #[serde(rename = "playlistid")]
+        id: usize,
+        position: usize,
+    },
+    // TODO: support other open mode
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct PlayerOpenOptions {
+    #[serde(rename = "playername")]
+    name: Option<String>,
+    repeat: Option<bool>,
+    // TODO: support other resume mode
+    resume: bool,
+    shuffled: Option<bool>,
+}
+
+define_method!(
+    #[doc="Start playback of either the playlist with the given ID, a slideshow with the pictures from the given directory or a single file or an item from the database."]
+    Player.Open {
+        item: PlayerOpenItem,
+        options: PlayerOpenOptions
+    } -> String
+);
+
+define_method!(
+    #[doc="Pauses or unpause playback and returns the new state"]
+    Player.PlayPause {
+        #[serde(rename = "playerid")]
+        id: u8,
+        play: crate::types::global::Toggle
+    } -> crate::types::player::Speed
+);
+
+impl PlayerPlayPause {
+    pub fn new(id: u8) -> Self {
+        Self {
+            id,
+            play: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerRotateValue {
+    Clockwise,
+    CounterClockwise,
+}
+
+define_method!(
+    #[doc="Rotates current picture"]
+    Player.Rotate {
+        #[serde(rename = "playerid")]
+        id: u8,
+        value: PlayerRotateValue
+    } -> String
+);
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerSeekStep {
+    SmallForward,
+    SmallBackward,
+    BigForward,
+    BigBackward,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerSeekMode {
+    Percentage(f64),
+    Time(crate::types::global::Time),
+    Step(PlayerSeekStep),
+    Seconds(isize),
+}
+
+define_method!(
+    #[doc="Seek through the playing item"]
+    Player.Seek {
+        #[serde(rename = "playerid")]
+        id: u8,
+        value: PlayerSeekMode
+    } -> PlayerSeekResponse
+);
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct PlayerSeekResponse {
+    pub percentage: f64,
+    pub time: crate::types::global::Time,
+    pub totaltime: crate::types::global::Time,
+}
+
+define_method!(
+    #[doc="Set the audio stream played by the player"]
+    Player.SetAudioStream {
+        #[serde(rename = "playerid")]
+        id: u8,
+        stream: crate::types::player::GoTo
+    } -> String
+);
+
+impl PlayerSetAudioStream {
+    /// Switches to the first of the `audiostreams` of the player in the given language
+    pub fn language(
+        id: u8,
+        streams: &[crate::types::player::audio::Stream],
+        language: &str,
+    ) -> Option<Self> {
+        let stream = streams.iter().find(|stream| stream.language == language)?;
+        Some(Self {
+            id,
+            stream: crate::types::player::GoTo::Absolute(stream.index),
+        })
+    }
+}
+
+define_method!(
+    #[doc="Turn partymode on or off"]
+    Player.SetPartymode {
+        #[serde(rename = "playerid")]
+        id: u8,
+        partymode: crate::types::global::Toggle
+    } -> String
+);
+
+define_method!(
+    #[doc="Set the repeat mode of the player"]
+    Player.SetRepeat {
+        #[serde(rename = "playerid")]
+        id: u8,
+        // TODO: handle `cycle` mode
+        repeat: crate::types::player::Repeat
+    } -> String
+);
+
+define_method!(
+    #[doc="Shuffle/Unshuffle items in the player"]
+    Player.SetShuffle {
+        #[serde(rename = "playerid")]
+        id: u8,
+        shuffle: crate::types::global::Toggle
+    } -> String
+);
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, serde_repr::Serialize_repr, serde_repr::Deserialize_repr,
+)]
+#[repr(i8)]
+pub enum PlayerSpeedValue {
+    Backward32 = -32,
+    Backward16 = -16,
+    Backward8 = -8,
+    Backward4 = -4,
+    Backward2 = -2,
+    Paused = 0,
+    Normal = 1,
+    Forward2 = 2,
+    Forward4 = 4,
+    Forward8 = 8,
+    Forward16 = 16,
+    Forward32 = 32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerSpeed {
+    Value(PlayerSpeedValue),
+    IncDec(crate::types::global::IncrementDecrement),
+}
+
+define_method!(
+    #[doc="Set the speed of the current playback"]
+    Player.SetSpeed {
+        #[serde(rename = "playerid")]
+        id: u8,
+        speed: PlayerSpeed
+    } -> crate::types::player::Speed
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerSubtitleState {
+    On,
+    Off,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerSubtitle {
+    Relative(crate::types::player::RelativePosition),
+    Absolute(usize),
+    State(PlayerSubtitleState),
+}
+
+define_method!(
+    #[doc="Set the subtitle displayed by the player"]
+    Player.SetSubtitle {
+        #[serde(rename = "playerid")]
+        id: u8,
+        subtitle: PlayerSubtitle,
+        #[doc="Whether to enable subtitles to be displayed after setting the new subtitle"]
+        enable: bool
+    } -> String
+);
+
+impl PlayerSetSubtitle {
+    /// Displays the first of the `subtitles` of the player in the given language
+    pub fn language(
+        id: u8,
+        subtitles: &[crate::types::video::streams::Subtitle],
+        language: &str,
+    ) -> Option<Self> {
+        let subtitle = subtitles
+            .iter()
+            .find(|subtitle| subtitle.language == language)?;
+        Some(Self {
+            id,
+            subtitle: PlayerSubtitle::Absolute(subtitle.index),
+            enable: true,
+        })
+    }
+}
+
+define_method!(
+    #[doc="Set the video stream played by the player"]
+    Player.SetVideoStream {
+        #[serde(rename = "playerid")]
+        id: u8,
+        stream: crate::types::player::GoTo
+    } -> String
+);
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerViewModeStep {
+    Increase,
+    Decrease,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerViewModeAdjust {
+    Step(PlayerViewModeStep),
+    Value(f64),
+}
+
+/// View mode values to change, the ones left to `None` being kept
+#[derive(Clone, Copy, Debug, Default, PartialEq, serde::Serialize)]
+pub struct PlayerCustomViewMode {
+    #[doc = "Zoom where 1.0 means 100%"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoom: Option<PlayerViewModeAdjust>,
+    #[doc = "Pixel aspect ratio where 1.0 means square pixel"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pixelratio: Option<PlayerViewModeAdjust>,
+    #[doc = "Vertical shift 1.0 means shift to bottom"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verticalshift: Option<PlayerViewModeAdjust>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonlinearstretch: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerViewMode {
+    Mode(crate::types::player::ViewMode),
+    Custom(PlayerCustomViewMode),
+}
+
+define_method!(
+    #[doc="Set view mode of video player"]
+    Player.SetViewMode {
+        viewmode: PlayerViewMode
+    } -> String
+);
+
+define_method!(
+    #[doc="Stops playback"]
+    Player.Stop {
+        #[serde(rename = "playerid")]
+        id: u8
+    } -> String
+);
+
+impl PlayerStop {
+    pub fn new(id: u8) -> Self {
+        Self { id }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlayerZoomStep {
+    In,
+    Out,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum PlayerZoomValue {
+    Step(PlayerZoomStep),
+    #[doc = "Zoom level, from 1 to 10"]
+    Level(u8),
+}
+
+define_method!(
+    #[doc="Zoom current picture"]
+    Player.Zoom {
+        #[serde(rename = "playerid")]
+        id: u8,
+        zoom: PlayerZoomValue
+    } -> String
+);
+
+// Playlist methods
+
+define_method!(
+    #[doc="Add item(s) to playlist"]
+    Playlist.Add {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        item: Vec<crate::types::playlist::Item>
+    } -> String
+);
+
+define_method!(
+    #[doc="Clear playlist"]
+    Playlist.Clear {
+        #[serde(rename = "playlistid")]
+        id: u8
+    } -> String
+);
+
+define_method!(
+    #[doc="Playlist.GetItems"]
+    idempotent Playlist.GetItems {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::list::fields::All>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> PlaylistGetItemsResponse
+);
+
+impl PlaylistGetItems {
+    pub fn all_properties(id: u8) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+            limits: None,
+            sort: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct PlaylistGetItemsResponse {
+    #[serde(default)]
+    pub items: Vec<crate::types::list::item::All>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Returns all existing playlists"]
+    idempotent Playlist.GetPlaylists {} -> Vec<crate::types::playlist::Playlist>
+);
+
+define_method!(
+    #[doc="Retrieves the values of the given properties"]
+    idempotent Playlist.GetProperties {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::playlist::property::Name>
+    } -> crate::types::playlist::property::Value
+);
+
+impl PlaylistGetProperties {
+    pub fn all(id: u8) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+define_method!(
+    #[doc="Insert item(s) into playlist. Does not work for picture playlists (aka slideshows)."]
+    Playlist.Insert {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        position: usize,
+        item: Vec<crate::types::playlist::Item>
+    } -> String
+);
+
+define_method!(
+    #[doc="Remove item from playlist. Does not work for picture playlists (aka slideshows)."]
+    Playlist.Remove {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        position: usize
+    } -> String
+);
+
+define_method!(
+    #[doc="Swap items in the playlist. Does not work for picture playlists (aka slideshows)."]
+    Playlist.Swap {
+        #[serde(rename = "playlistid")]
+        id: u8,
+        position1: usize,
+        position2: usize
+    } -> String
+);
+
+// Profiles methods
+
+define_method!(
+    #[doc="Retrieve the current profile"]
+    #[derive(Default)]
+    idempotent Profiles.GetCurrentProfile {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::profiles::fields::Profile>
+    } -> crate::types::profiles::details::Profile
+);
+
+define_method!(
+    #[doc="Retrieve all profiles"]
+    #[derive(Default)]
+    idempotent Profiles.GetProfiles {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::profiles::fields::Profile>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> ProfilesGetProfilesResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ProfilesGetProfilesResponse {
+    #[serde(default)]
+    pub profiles: Vec<crate::types::profiles::details::Profile>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Load the specified profile"]
+    Profiles.LoadProfile {
+        #[doc="Profile name"]
+        profile: String,
+        #[doc="Prompt for password"]
+        prompt: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        password: Option<crate::types::profiles::Password>
+    } -> String
+);
+
+// Settings methods
+
+define_method!(
+    #[doc="Retrieves all setting categories"]
+    #[derive(Default)]
+    idempotent Settings.GetCategories {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        level: Option<crate::types::setting::Level>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        section: Option<String>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::setting::fields::Category>
+    } -> SettingsGetCategoriesResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SettingsGetCategoriesResponse {
+    #[serde(default)]
+    pub categories: Vec<crate::types::setting::details::Category>,
+}
+
+define_method!(
+    #[doc="Retrieves all setting sections"]
+    #[derive(Default)]
+    idempotent Settings.GetSections {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        level: Option<crate::types::setting::Level>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::setting::fields::Section>
+    } -> SettingsGetSectionsResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SettingsGetSectionsResponse {
+    #[serde(default)]
+    pub sections: Vec<crate::types::setting::details::Section>,
+}
+
+define_method!(
+    #[doc="Retrieves the value of a setting"]
+    idempotent Settings.GetSettingValue {
+        setting: String
+    } -> SettingsGetSettingValueResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SettingsGetSettingValueResponse {
+    pub value: crate::types::setting::Value,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct SettingsGetSettingsFilter {
+    pub section: String,
+    pub category: String,
+}
+
+define_method!(
+    #[doc="Retrieves all settings"]
+    #[derive(Default)]
+    idempotent Settings.GetSettings {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        level: Option<crate::types::setting::Level>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<SettingsGetSettingsFilter>
+    } -> SettingsGetSettingsResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct SettingsGetSettingsResponse {
+    #[serde(default)]
+    pub settings: Vec<crate::types::setting::details::Setting>,
+}
+
+define_method!(
+    #[doc="Resets the value of a setting"]
+    Settings.ResetSettingValue {
+        setting: String
+    } -> String
+);
+
+define_method!(
+    #[doc="Changes the value of a setting"]
+    Settings.SetSettingValue {
+        setting: String,
+        value: crate::types::setting::Value
+    } -> bool
+);
+
+// System methods
+
+define_method!(
+    #[doc="Ejects or closes the optical disc drive (if available)"]
+    System.EjectOpticalDrive {} -> String
+);
+
+define_method!(
+    #[doc="Retrieves the values of the given properties"]
+    idempotent System.GetProperties {
+        properties: enumset::EnumSet<crate::types::system::property::Name>
+    } -> crate::types::system::property::Value
+);
+
+impl SystemGetProperties {
+    pub fn all() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[cfg(feature = "power")]
+define_method!(
+    #[doc="Puts the system running Kodi into hibernate mode"]
+    System.Hibernate {} -> String
+);
+
+#[cfg(feature = "power")]
+define_method!(
+    #[doc="Reboots the system running Kodi"]
+    System.Reboot {} -> String
+);
+
+#[cfg(feature = "power")]
+define_method!(
+    #[doc="Shuts the system running Kodi down"]
+    System.Shutdown {} -> String
+);
+
+#[cfg(feature = "power")]
+define_method!(
+    #[doc="Suspends the system running Kodi"]
+    System.Suspend {} -> String
+);
+
+// Textures methods
+
+define_method!(
+    #[doc="Retrieve all textures"]
+    #[derive(Default)]
+    idempotent Textures.GetTextures {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::textures::fields::Texture>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<crate::types::list::filter::Textures>
+    } -> TexturesGetTexturesResponse
+);
+
+impl TexturesGetTextures {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            filter: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct TexturesGetTexturesResponse {
+    #[serde(default)]
+    pub textures: Vec<crate::types::textures::details::Texture>,
+}
+
+define_method!(
+    #[doc="Remove the specified texture"]
+    Textures.RemoveTexture {
+        #[serde(rename = "textureid")]
+        id: usize
+    } -> String
+);
+
+// VideoLibrary methods
+
+define_method!(
+    #[doc="Cleans the video library for non-existent items"]
+    #[derive(Default)]
+    VideoLibrary.Clean {
+        #[doc="Whether or not to show the progress bar or any other GUI dialog"]
+        showdialogs: bool,
+        #[doc="Content type to clean for"]
+        content: crate::types::video::CleanContent,
+        #[doc="Path to the directory to clean up; performs a global cleanup if not specified"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        directory: Option<String>
+    } -> String
+);
+
+/// Where and how the video library is exported
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum VideoLibraryExportOptions {
+    /// Exports the whole library to a single `kodi_videodb.xml` file in the given directory
+    SingleFile { path: String },
+    /// Exports each item to a separate NFO file, next to its video files
+    SeparateFiles {
+        #[doc = "Whether to export thumbnails and fanart images"]
+        images: bool,
+        #[doc = "Whether to overwrite existing exported files"]
+        overwrite: bool,
+        #[doc = "Whether to export the thumbnails of the actors"]
+        actorthumbs: bool,
+    },
+}
+
+define_method!(
+    #[doc="Exports all items from the video library"]
+    VideoLibrary.Export {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        options: Option<VideoLibraryExportOptions>
+    } -> String
+);
+
+define_method!(
+    #[doc="Retrieve details about a specific tv show episode"]
+    idempotent VideoLibrary.GetEpisodeDetails {
+        #[serde(rename = "episodeid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Episode>
+    } -> VideoLibraryGetEpisodeDetailsResponse
+);
+
+impl VideoLibraryGetEpisodeDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetEpisodeDetailsResponse {
+    EpisodeDetails(crate::types::video::details::Episode),
+}
+
+/// The genre, genre id and actor filters require the tv show to be set
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetEpisodesFilterSimple {
+    GenreId(usize),
+    Genre(String),
+    Year(usize),
+    Actor(String),
+    Director(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum VideoLibraryGetEpisodesFilter {
+    Simple(VideoLibraryGetEpisodesFilterSimple),
+    Complex(crate::types::list::filter::Episodes),
+}
+
+impl From<VideoLibraryGetEpisodesFilterSimple> for VideoLibraryGetEpisodesFilter {
+    fn from(value: VideoLibraryGetEpisodesFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::Episodes> for VideoLibraryGetEpisodesFilter {
+    fn from(value: crate::types::list::filter::Episodes) -> Self {
+        Self::Complex(value)
+    }
+}
+
+define_method!(
+    #[doc="Retrieve all tv show episodes"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetEpisodes {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tvshowid: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        season: Option<usize>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Episode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<VideoLibraryGetEpisodesFilter>
+    } -> VideoLibraryGetEpisodesResponse
+);
+
+impl VideoLibraryGetEpisodes {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetEpisodesResponse {
+    #[serde(default)]
+    pub episodes: Vec<crate::types::video::details::Episode>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve all genres"]
+    idempotent VideoLibrary.GetGenres {
+        #[serde(rename = "type")]
+        kind: crate::types::video::Content,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::library::fields::Genre>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetGenresResponse
+);
+
+impl VideoLibraryGetGenres {
+    pub fn all_properties(kind: crate::types::video::Content) -> Self {
+        Self {
+            kind,
+            properties: enumset::EnumSet::all(),
+            limits: None,
+            sort: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetGenresResponse {
+    #[serde(default)]
+    pub genres: Vec<crate::types::library::details::Genre>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve all in progress tvshows"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetInProgressTVShows {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::TVShow>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetTVShowsResponse
+);
+
+define_method!(
+    #[doc="Retrieve details about a specific movie"]
+    idempotent VideoLibrary.GetMovieDetails {
+        #[serde(rename = "movieid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Movie>
+    } -> VideoLibraryGetMovieDetailsResponse
+);
+
+impl VideoLibraryGetMovieDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetMovieDetailsResponse {
+    MovieDetails(crate::types::video::details::Movie),
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct VideoLibraryGetMovieSetDetailsMovies {
+    #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+    pub properties: enumset::EnumSet<crate::types::video::fields::Movie>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<crate::types::list::Limits>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<crate::types::list::Sort>,
+}
+
+define_method!(
+    #[doc="Retrieve details about a specific movie set"]
+    idempotent VideoLibrary.GetMovieSetDetails {
+        #[serde(rename = "setid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::MovieSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        movies: Option<VideoLibraryGetMovieSetDetailsMovies>
+    } -> VideoLibraryGetMovieSetDetailsResponse
+);
+
+impl VideoLibraryGetMovieSetDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+            movies: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetMovieSetDetailsResponse {
+    SetDetails(crate::types::video::details::MovieSetExtended),
+}
+
+define_method!(
+    #[doc="Retrieve all movie sets"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetMovieSets {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::MovieSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetMovieSetsResponse
+);
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetMovieSetsResponse {
+    #[serde(default)]
+    pub sets: Vec<crate::types::video::details::MovieSet>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetMoviesFilterSimple {
+    GenreId(usize),
+    Genre(String),
+    Year(usize),
+    Actor(String),
+    Director(String),
+    Studio(String),
+    Country(String),
+    SetId(usize),
+    Set(String),
+    Tag(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum VideoLibraryGetMoviesFilter {
+    Simple(VideoLibraryGetMoviesFilterSimple),
+    Complex(crate::types::list::filter::Movies),
+}
+
+impl From<VideoLibraryGetMoviesFilterSimple> for VideoLibraryGetMoviesFilter {
+    fn from(value: VideoLibraryGetMoviesFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::Movies> for VideoLibraryGetMoviesFilter {
+    fn from(value: crate::types::list::filter::Movies) -> Self {
+        Self::Complex(value)
+    }
+}
+
+define_method!(
+    #[doc="Retrieve all movies"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetMovies {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Movie>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<VideoLibraryGetMoviesFilter>
+    } -> VideoLibraryGetMoviesResponse
+);
+
+impl VideoLibraryGetMovies {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetMoviesResponse {
+    #[serde(default)]
+    pub movies: Vec<crate::types::video::details::Movie>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve details about a specific music video"]
+    idempotent VideoLibrary.GetMusicVideoDetails {
+        #[serde(rename = "musicvideoid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::MusicVideo>
+    } -> VideoLibraryGetMusicVideoDetailsResponse
+);
+
+impl VideoLibraryGetMusicVideoDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetMusicVideoDetailsResponse {
+    MusicVideoDetails(crate::types::video::details::MusicVideo),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetMusicVideosFilterSimple {
+    Artist(String),
+    GenreId(usize),
+    Genre(String),
+    Year(usize),
+    Director(String),
+    Studio(String),
+    Tag(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum VideoLibraryGetMusicVideosFilter {
+    Simple(VideoLibraryGetMusicVideosFilterSimple),
+    Complex(crate::types::list::filter::MusicVideos),
+}
+
+impl From<VideoLibraryGetMusicVideosFilterSimple> for VideoLibraryGetMusicVideosFilter {
+    fn from(value: VideoLibraryGetMusicVideosFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::MusicVideos> for VideoLibraryGetMusicVideosFilter {
+    fn from(value: crate::types::list::filter::MusicVideos) -> Self {
+        Self::Complex(value)
+    }
+}
+
+define_method!(
+    #[doc="Retrieve all music videos"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetMusicVideos {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::MusicVideo>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<VideoLibraryGetMusicVideosFilter>
+    } -> VideoLibraryGetMusicVideosResponse
+);
+
+impl VideoLibraryGetMusicVideos {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetMusicVideosResponse {
+    #[serde(default)]
+    pub musicvideos: Vec<crate::types::video::details::MusicVideo>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve all recently added tv episodes"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetRecentlyAddedEpisodes {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Episode>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetEpisodesResponse
+);
+
+define_method!(
+    #[doc="Retrieve all recently added movies"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetRecentlyAddedMovies {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Movie>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetMoviesResponse
+);
+
+define_method!(
+    #[doc="Retrieve all recently added music videos"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetRecentlyAddedMusicVideos {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::MusicVideo>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetMusicVideosResponse
+);
+
+define_method!(
+    #[doc="Retrieve details about a specific tv show season"]
+    idempotent VideoLibrary.GetSeasonDetails {
+        #[serde(rename = "seasonid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Season>
+    } -> VideoLibraryGetSeasonDetailsResponse
+);
+
+impl VideoLibraryGetSeasonDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetSeasonDetailsResponse {
+    SeasonDetails(crate::types::video::details::Season),
+}
+
+define_method!(
+    #[doc="Retrieve all tv seasons"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetSeasons {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tvshowid: Option<usize>,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::Season>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetSeasonsResponse
+);
+
+impl VideoLibraryGetSeasons {
+    pub fn all_properties(tvshowid: Option<usize>) -> Self {
+        Self {
+            tvshowid,
+            properties: enumset::EnumSet::all(),
+            limits: None,
+            sort: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetSeasonsResponse {
+    #[serde(default)]
+    pub seasons: Vec<crate::types::video::details::Season>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve details about a specific tv show"]
+    idempotent VideoLibrary.GetTVShowDetails {
+        #[serde(rename = "tvshowid")]
+        id: usize,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::TVShow>
+    } -> VideoLibraryGetTVShowDetailsResponse
+);
+
+impl VideoLibraryGetTVShowDetails {
+    pub fn all_properties(id: usize) -> Self {
+        Self {
+            id,
+            properties: enumset::EnumSet::all(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetTVShowDetailsResponse {
+    TVShowDetails(crate::types::video::details::TVShow),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoLibraryGetTVShowsFilterSimple {
+    GenreId(usize),
+    Genre(String),
+    Year(usize),
+    Actor(String),
+    Studio(String),
+    Tag(String),
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum VideoLibraryGetTVShowsFilter {
+    Simple(VideoLibraryGetTVShowsFilterSimple),
+    Complex(crate::types::list::filter::TVShows),
+}
+
+impl From<VideoLibraryGetTVShowsFilterSimple> for VideoLibraryGetTVShowsFilter {
+    fn from(value: VideoLibraryGetTVShowsFilterSimple) -> Self {
+        Self::Simple(value)
+    }
+}
+
+impl From<crate::types::list::filter::TVShows> for VideoLibraryGetTVShowsFilter {
+    fn from(value: crate::types::list::filter::TVShows) -> Self {
+        Self::Complex(value)
+    }
+}
+
+define_method!(
+    #[doc="Retrieve all tv shows"]
+    #[derive(Default)]
+    idempotent VideoLibrary.GetTVShows {
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::video::fields::TVShow>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filter: Option<VideoLibraryGetTVShowsFilter>
+    } -> VideoLibraryGetTVShowsResponse
+);
+
+impl VideoLibraryGetTVShows {
+    pub fn all_properties() -> Self {
+        Self {
+            properties: enumset::EnumSet::all(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetTVShowsResponse {
+    #[serde(default)]
+    pub tvshows: Vec<crate::types::video::details::TVShow>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Retrieve all tags"]
+    idempotent VideoLibrary.GetTags {
+        #[serde(rename = "type")]
+        kind: crate::types::video::Content,
+        #[serde(skip_serializing_if = "enumset::EnumSet::is_empty")]
+        properties: enumset::EnumSet<crate::types::library::fields::Tag>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<crate::types::list::Limits>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sort: Option<crate::types::list::Sort>
+    } -> VideoLibraryGetTagsResponse
+);
+
+impl VideoLibraryGetTags {
+    pub fn all_properties(kind: crate::types::video::Content) -> Self {
+        Self {
+            kind,
+            properties: enumset::EnumSet::all(),
+            limits: None,
+            sort: None,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct VideoLibraryGetTagsResponse {
+    #[serde(default)]
+    pub tags: Vec<crate::types::library::details::Tag>,
+    pub limits: crate::types::list::LimitsReturned,
+}
+
+define_method!(
+    #[doc="Refresh the given episode in the library"]
+    VideoLibrary.RefreshEpisode {
+        #[serde(rename = "episodeid")]
+        id: usize,
+        #[doc="Whether or not to ignore a local NFO if present"]
+        ignorenfo: bool,
+        #[doc="Title to use for searching (instead of determining it from the item's filename/path)"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Refresh the given movie in the library"]
+    VideoLibrary.RefreshMovie {
+        #[serde(rename = "movieid")]
+        id: usize,
+        #[doc="Whether or not to ignore a local NFO if present"]
+        ignorenfo: bool,
+        #[doc="Title to use for searching (instead of determining it from the item's filename/path)"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Refresh the given music video in the library"]
+    VideoLibrary.RefreshMusicVideo {
+        #[serde(rename = "musicvideoid")]
+        id: usize,
+        #[doc="Whether or not to ignore a local NFO if present"]
+        ignorenfo: bool,
+        #[doc="Title to use for searching (instead of determining it from the item's filename/path)"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Refresh the given tv show in the library"]
+    VideoLibrary.RefreshTVShow {
+        #[serde(rename = "tvshowid")]
+        id: usize,
+        #[doc="Whether or not to ignore a local NFO if present"]
+        ignorenfo: bool,
+        #[doc="Whether or not to refresh all episodes belonging to the TV show"]
+        refreshepisodes: bool,
+        #[doc="Title to use for searching (instead of determining it from the item's filename/path)"]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Removes the given episode from the library"]
+    VideoLibrary.RemoveEpisode {
+        #[serde(rename = "episodeid")]
+        id: usize
+    } -> String
+);
+
+define_method!(
+    #[doc="Removes the given movie from the library"]
+    VideoLibrary.RemoveMovie {
+        #[serde(rename = "movieid")]
+        id: usize
+    } -> String
+);
+
+define_method!(
+    #[doc="Removes the given music video from the library"]
+    VideoLibrary.RemoveMusicVideo {
+        #[serde(rename = "musicvideoid")]
+        id: usize
+    } -> String
+);
+
+define_method!(
+    #[doc="Removes the given tv show from the library"]
+    VideoLibrary.RemoveTVShow {
+        #[serde(rename = "tvshowid")]
+        id: usize
+    } -> String
+);
+
+define_method!(
+    #[doc="Scans the video sources for new library items"]
+    VideoLibrary.Scan {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        directory: Option<String>,
+        #[doc="Whether or not to show the progress bar or any other GUI dialog"]
+        showdialogs: bool
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given episode with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetEpisodeDetails {
+        #[serde(rename = "episodeid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        playcount: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        runtime: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        director: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plot: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rating: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        votes: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lastplayed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        writer: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        firstaired: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        productioncode: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        season: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        episode: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        originaltitle: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumbnail: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fanart: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        resume: Option<crate::types::video::Resume>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        userrating: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ratings: Option<crate::types::video::Ratings>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dateadded: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        uniqueid: Option<std::collections::HashMap<String, Option<String>>>
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given movie with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetMovieDetails {
+        #[serde(rename = "movieid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        playcount: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        runtime: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        director: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        studio: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        year: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plot: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        genre: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rating: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mpaa: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        imdbnumber: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        votes: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lastplayed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        originaltitle: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        trailer: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tagline: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plotoutline: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        writer: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        country: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        top250: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sorttitle: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        set: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        showlink: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumbnail: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fanart: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        resume: Option<crate::types::video::Resume>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        userrating: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ratings: Option<crate::types::video::Ratings>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dateadded: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        premiered: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        uniqueid: Option<std::collections::HashMap<String, Option<String>>>
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given movie set with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetMovieSetDetails {
+        #[serde(rename = "setid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plot: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given music video with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetMusicVideoDetails {
+        #[serde(rename = "musicvideoid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        playcount: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        runtime: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        director: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        studio: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        year: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plot: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        album: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        artist: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        genre: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        track: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lastplayed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumbnail: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fanart: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        resume: Option<crate::types::video::Resume>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rating: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        userrating: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dateadded: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        premiered: Option<String>
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given season with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetSeasonDetails {
+        #[serde(rename = "seasonid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        userrating: Option<usize>
+    } -> String
+);
+
+define_method!(
+    #[doc="Update the given tvshow with the given details. Details left to `None` are not changed."]
+    #[derive(Default)]
+    VideoLibrary.SetTVShowDetails {
+        #[serde(rename = "tvshowid")]
+        id: usize,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        playcount: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        studio: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        plot: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        genre: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        rating: Option<f64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        mpaa: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        imdbnumber: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        premiered: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        votes: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        lastplayed: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        originaltitle: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sorttitle: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        episodeguide: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        thumbnail: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fanart: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tag: Option<Vec<String>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        art: Option<crate::types::media::ArtworkSet>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        userrating: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        ratings: Option<crate::types::video::Ratings>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        dateadded: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        runtime: Option<usize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        status: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        uniqueid: Option<std::collections::HashMap<String, Option<String>>>
+    } -> String
+);
+
+// XBMC methods
+
+define_method!(
+    #[doc="Retrieve info booleans about Kodi and the system"]
+    idempotent XBMC.GetInfoBooleans {
+        booleans: Vec<crate::types::xbmc::InfoBoolean>
+    } -> std::collections::HashMap<crate::types::xbmc::InfoBoolean, bool>
+);
+
+define_method!(
+    #[doc="Retrieve info labels about Kodi and the system"]
+    idempotent XBMC.GetInfoLabels {
+        labels: Vec<crate::types::xbmc::InfoLabel>
+    } -> std::collections::HashMap<crate::types::xbmc::InfoLabel, String>
+);
+
+#[test]
+fn test_video_library() {
+    use crate::types::list::filter::{fields, rule::Rule, Operators};
+
+    let method = VideoLibraryGetMovies {
+        filter: Some(VideoLibraryGetMoviesFilterSimple::SetId(3).into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&method).unwrap(),
+        serde_json::json!({"filter": {"setid": 3}})
+    );
+    let method = VideoLibraryGetTVShows {
+        filter: Some(
+            crate::types::list::filter::TVShows::from(Rule {
+                operator: Operators::GreaterThan,
+                value: "2".to_owned().into(),
+                field: fields::TVShows::NumWatched,
+            })
+            .into(),
+        ),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&method).unwrap(),
+        serde_json::json!({"filter": {"field": "numwatched", "operator": "greaterthan", "value": "2"}})
+    );
+    let method = VideoLibrarySetMovieDetails {
+        id: 42,
+        playcount: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&method).unwrap(),
+        serde_json::json!({"movieid": 42, "playcount": 1})
+    );
+
+    let response: VideoLibraryGetMoviesResponse = serde_json::from_str(
+        r#"{"limits":{"end":1,"start":0,"total":1},"movies":[{"cast":[{"name":"Keanu Reeves","order":0,"role":"Neo","thumbnail":""}],"label":"The Matrix","movieid":1,"ratings":{"imdb":{"default":true,"rating":8.7,"votes":1900000}},"streamdetails":{"audio":[{"channels":6,"codec":"dts","language":"eng"}],"subtitle":[],"video":[{"aspect":2.4,"codec":"h264","duration":8160,"height":800,"width":1920}]},"uniqueid":{"imdb":"tt0133093"}}]}"#,
+    )
+    .unwrap();
+    let movie = &response.movies[0];
+    assert_eq!(movie.cast[0].role, "Neo");
+    assert_eq!(movie.ratings.as_ref().unwrap()["imdb"].votes, Some(1900000));
+    assert_eq!(movie.streamdetails.as_ref().unwrap().video[0].width, 1920);
+    assert_eq!(movie.uniqueid.as_ref().unwrap()["imdb"], "tt0133093");
+}
+
+#[test]
+fn test_pvr() {
+    use crate::types::global::Weekday;
+    use crate::types::pvr::{AllChannels, ChannelGroupId, TimerState};
+
+    assert_eq!(
+        serde_json::to_value(PVRRecord::default()).unwrap(),
+        serde_json::json!({"record": "toggle", "channel": "current"})
+    );
+    assert_eq!(
+        serde_json::to_value(PVRGetChannels::all_properties(ChannelGroupId::All(
+            AllChannels::AllRadio
+        )))
+        .unwrap()["channelgroupid"],
+        "allradio"
+    );
+    assert_eq!(
+        serde_json::to_value(PVRGetChannelGroupDetails {
+            id: 4.into(),
+            channels: None
+        })
+        .unwrap(),
+        serde_json::json!({"channelgroupid": 4})
+    );
+
+    let response: PVRGetTimersResponse = serde_json::from_str(
+        r#"{"limits":{"end":1,"start":0,"total":1},"timers":[{"label":"News","timerid":3,"istimerrule":true,"state":"conflict_notok","weekdays":["monday","friday"]}]}"#,
+    )
+    .unwrap();
+    let timer = &response.timers[0];
+    assert_eq!(timer.state, Some(TimerState::ConflictNotOk));
+    assert_eq!(timer.weekdays, Weekday::Monday | Weekday::Friday);
+}
+
+#[test]
+fn test_gui() {
+    use crate::types::gui::{property::Value, NotificationImage, Window};
+
+    let method = GUIShowNotification {
+        image: Some(NotificationImage::Warning),
+        displaytime: Some(10000),
+        ..GUIShowNotification::new("Backup".to_owned(), "Finished with errors".to_owned())
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"title": "Backup", "message": "Finished with errors", "image": "warning", "displaytime": 10000})
+    );
+    assert_eq!(
+        serde_json::to_value(GUIActivateWindow::new(Window::PvrOsdChannels)).unwrap(),
+        serde_json::json!({"window": "pvrosdchannels"})
+    );
+
+    let value: Value = serde_json::from_str(
+        r#"{"currentwindow":{"id":10000,"label":"Home"},"stereoscopicmode":{"label":"Disabled","mode":"off"}}"#,
+    )
+    .unwrap();
+    assert_eq!(value.currentwindow.unwrap().id, 10000);
+}
+
+#[test]
+fn test_settings() {
+    use crate::types::setting::details::Definition;
+    use crate::types::setting::Value;
+
+    assert_eq!(
+        serde_json::to_value(SettingsSetSettingValue {
+            setting: "musicplayer.crossfade".to_owned(),
+            value: 5.into(),
+        })
+        .unwrap(),
+        serde_json::json!({"setting": "musicplayer.crossfade", "value": 5})
+    );
+
+    let response: SettingsGetSettingValueResponse =
+        serde_json::from_str(r#"{"value":"ALSA:default"}"#).unwrap();
+    assert_eq!(response.value, Value::from("ALSA:default"));
+
+    let response: SettingsGetSettingsResponse = serde_json::from_str(
+        r#"{"settings":[
+            {"control":{"delayed":false,"format":"integer","type":"list","multiselect":false},"default":0,"enabled":true,"id":"musicplayer.crossfade","label":"Crossfade between songs","level":"basic","maximum":15,"minimum":0,"parent":"","step":1,"type":"integer","value":3},
+            {"default":"PulseAudio:Default","enabled":true,"id":"audiooutput.audiodevice","label":"Audio output device","level":"basic","options":[{"label":"Default","value":"PulseAudio:Default"}],"allowempty":false,"type":"string","value":"PulseAudio:Default"},
+            {"default":["a"],"definition":{"allowempty":true,"default":"","enabled":true,"id":"element","label":"","level":"basic","type":"string","value":""},"delimiter":",","elementtype":"string","enabled":true,"id":"list","label":"List","level":"expert","type":"list","value":["a","b"]}
+        ]}"#,
+    )
+    .unwrap();
+    match &response.settings[0].definition {
+        Definition::Integer(setting) => {
+            assert_eq!(setting.value, 3);
+            assert_eq!(setting.maximum, Some(15));
+        }
+        definition => panic!("unexpected definition {:?}", definition),
+    }
+    match &response.settings[1].definition {
+        Definition::String(setting) => assert_eq!(setting.options[0].label, "Default"),
+        definition => panic!("unexpected definition {:?}", definition),
+    }
+    match &response.settings[2].definition {
+        Definition::List(setting) => assert_eq!(setting.value.len(), 2),
+        definition => panic!("unexpected definition {:?}", definition),
+    }
+}
+
+#[test]
+fn test_xbmc() {
+    use crate::types::xbmc::{InfoBoolean, InfoLabel};
+
+    let method = XBMCGetInfoLabels {
+        labels: vec![
+            InfoLabel::MusicPlayerCodec,
+            InfoLabel::Other("Skin.String(Foo)".to_owned()),
+        ],
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"labels": ["MusicPlayer.Codec", "Skin.String(Foo)"]})
+    );
+
+    let labels: <XBMCGetInfoLabels as crate::KodiMethod>::Response =
+        serde_json::from_str(r#"{"MusicPlayer.Codec":"flac","Skin.String(Foo)":"bar"}"#).unwrap();
+    assert_eq!(labels[&InfoLabel::MusicPlayerCodec], "flac");
+    assert_eq!(labels[&InfoLabel::from("Skin.String(Foo)")], "bar");
+
+    let booleans: <XBMCGetInfoBooleans as crate::KodiMethod>::Response =
+        serde_json::from_str(r#"{"Library.IsScanningMusic":true}"#).unwrap();
+    assert!(booleans[&InfoBoolean::LibraryIsScanningMusic]);
+}
+
+#[test]
+fn test_addons() {
+    use crate::types::addon::{details::Broken, Content, Selection, Type};
+    use crate::types::files::Media;
+
+    let method = AddonsGetAddons {
+        enabled: Some(Selection::All),
+        ..AddonsGetAddons::plugins(Content::Audio)
+    };
+    let params = serde_json::to_value(method).unwrap();
+    assert_eq!(params["type"], "xbmc.python.pluginsource");
+    assert_eq!(params["content"], "audio");
+    assert_eq!(params["enabled"], "all");
+    assert!(params.get("installed").is_none());
+
+    let response: AddonsGetAddonsResponse = serde_json::from_value(serde_json::json!({
+        "addons": [{
+            "addonid": "plugin.audio.radio_de",
+            "broken": "Not maintained anymore",
+            "dependencies": [{"addonid": "xbmc.python", "optional": false, "version": "3.0.0"}],
+            "enabled": true,
+            "label": "Radio",
+            "type": "xbmc.python.pluginsource",
+        }],
+        "limits": {"end": 1, "start": 0, "total": 1},
+    }))
+    .unwrap();
+    let addon = &response.addons[0];
+    assert_eq!(addon.kind, Type::PluginSource);
+    assert_eq!(
+        addon.broken,
+        Some(Broken::Reason("Not maintained anymore".to_owned()))
+    );
+    assert_eq!(addon.dependencies[0].addonid, "xbmc.python");
+
+    let method = FilesGetDirectory::plugin(&addon.addonid, "/?mode=stations", Media::Music);
+    assert_eq!(
+        method.directory,
+        "plugin://plugin.audio.radio_de/?mode=stations"
+    );
+}
+
+#[test]
+fn test_favourites_textures() {
+    use crate::types::favourite::Type;
+    use crate::types::gui::Window;
+    use crate::types::list::filter::{fields, rule, Filter, Operators};
+
+    let method = FavouritesAddFavourite::window("Music".to_owned(), Window::Music);
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"title": "Music", "type": "window", "window": "music"})
+    );
+
+    let response: FavouritesGetFavouritesResponse = serde_json::from_value(serde_json::json!({
+        "favourites": [{"title": "Amazon", "type": "androidapp", "path": "com.amazon.avod"}],
+        "limits": {"end": 1, "start": 0, "total": 1},
+    }))
+    .unwrap();
+    assert_eq!(response.favourites[0].kind, Type::AndroidApp);
+
+    let mut filter = Filter::from(rule::Textures {
+        field: fields::Textures::LastUsed,
+        operator: Operators::Before,
+        value: "2020-01-01".to_owned().into(),
+    });
+    filter.and(Filter::from(rule::Textures {
+        field: fields::Textures::UseCount,
+        operator: Operators::LessThan,
+        value: "2".to_owned().into(),
+    }));
+    let method = TexturesGetTextures {
+        filter: Some(filter),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"filter": {"and": [
+            {"field": "lastused", "operator": "before", "value": "2020-01-01"},
+            {"field": "usecount", "operator": "lessthan", "value": "2"},
+        ]}})
+    );
+    assert_eq!(
+        serde_json::to_value(TexturesRemoveTexture { id: 42 }).unwrap(),
+        serde_json::json!({"textureid": 42})
+    );
+    let response: TexturesGetTexturesResponse =
+        serde_json::from_value(serde_json::json!({})).unwrap();
+    assert!(response.textures.is_empty());
+}
+
+#[test]
+fn test_audio_library() {
+    let method = AudioLibraryGetRecentlyAddedSongs {
+        albumlimit: Some(5),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"albumlimit": 5})
+    );
+
+    let response: AudioLibraryGetSongDetailsResponse = serde_json::from_value(serde_json::json!({
+        "songdetails": {"label": "Intro", "songid": 42, "title": "Intro"},
+    }))
+    .unwrap();
+    let AudioLibraryGetSongDetailsResponse::SongDetails(song) = response;
+    assert_eq!(song.songid, 42);
+
+    let response: AudioLibraryGetRolesResponse = serde_json::from_value(serde_json::json!({
+        "roles": [{"label": "Composer", "roleid": 2, "title": "Composer"}],
+        "limits": {"end": 1, "start": 0, "total": 1},
+    }))
+    .unwrap();
+    assert_eq!(response.roles[0].roleid, 2);
+
+    let properties: <AudioLibraryGetProperties as crate::KodiMethod>::Response =
+        serde_json::from_value(serde_json::json!({
+            "librarylastupdated": "2021-03-14 15:09:26",
+            "missingartistid": -1,
+        }))
+        .unwrap();
+    assert_eq!(
+        properties.librarylastupdated.as_deref(),
+        Some("2021-03-14 15:09:26")
+    );
+    assert_eq!(properties.missingartistid, None);
+
+    // Only the properties of the shipped schema are requested
+    assert_eq!(
+        serde_json::to_value(AudioLibraryGetProperties::all()).unwrap(),
+        serde_json::json!({"properties": ["missingartistid", "librarylastupdated"]})
+    );
+}
+
+#[test]
+fn test_audio_library_changes() {
+    use crate::types::audio::changes::Song;
+
+    let method = AudioLibrarySetAlbumDetails {
+        id: 7,
+        changes: crate::types::audio::changes::Album::default()
+            .genre(vec!["Jazz".to_owned()])
+            .userrating(8),
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"albumid": 7, "genre": ["Jazz"], "userrating": 8})
+    );
+
+    let old: crate::types::audio::details::Song = serde_json::from_value(serde_json::json!({
+        "label": "Intro", "songid": 42, "title": "Intro", "genre": ["Rock"], "rating": 2.0,
+        "userrating": 3, "year": 1999,
+    }))
+    .unwrap();
+    let mut new = old.clone();
+    new.genre = vec!["Rock".to_owned(), "Pop".to_owned()];
+    new.rating = Some(4.5);
+    new.year = None;
+    let changes = Song::diff(&old, &new);
+    assert_eq!(
+        changes,
+        Song::default()
+            .genre(vec!["Rock".to_owned(), "Pop".to_owned()])
+            .rating(4.5)
+    );
+    assert!(Song::diff(&old, &old).is_empty());
+    assert_eq!(
+        serde_json::to_value(AudioLibrarySetSongDetails { id: 42, changes }).unwrap(),
+        serde_json::json!({"songid": 42, "genre": ["Rock", "Pop"], "rating": 4.5})
+    );
+
+    // Lists that were not fetched are empty and must not wipe the tags
+    let old: crate::types::audio::details::Song = serde_json::from_value(serde_json::json!({
+        "label": "Intro", "songid": 42, "genre": ["Rock"], "artist": ["Foo"],
+        "albumartist": ["Foo"], "mood": ["Calm"],
+    }))
+    .unwrap();
+    let new: crate::types::audio::details::Song = serde_json::from_value(serde_json::json!({
+        "label": "Intro", "songid": 42,
+    }))
+    .unwrap();
+    assert!(Song::diff(&old, &new).is_empty());
+
+    let mut new = old.clone();
+    new.art = serde_json::from_value(serde_json::json!({"thumb": "image://thumb.jpg/"})).unwrap();
+    assert_eq!(
+        Song::diff(&old, &new),
+        Song::default().art(
+            vec![("thumb".to_owned(), Some("image://thumb.jpg/".to_owned()))]
+                .into_iter()
+                .collect()
+        )
+    );
+}
+
+#[test]
+fn test_player_streams() {
+    use crate::types::player::property::Value;
+    use crate::types::player::ViewMode;
+
+    let properties: Value = serde_json::from_value(serde_json::json!({
+        "audiostreams": [
+            {"bitrate": 0, "channels": 6, "codec": "ac3", "index": 0, "language": "eng", "name": ""},
+            {"bitrate": 0, "channels": 2, "codec": "aac", "index": 1, "language": "fre", "name": ""},
+        ],
+        "currentsubtitle": {},
+        "currentvideostream": {
+            "codec": "h264", "height": 1080, "index": 0, "language": "", "name": "", "width": 1920
+        },
+        "subtitles": [{"index": 0, "language": "fre", "name": "Forced"}],
+    }))
+    .unwrap();
+    assert_eq!(properties.currentsubtitle, None);
+    let video = properties.currentvideostream.unwrap();
+    assert_eq!((video.codec.as_str(), video.width), ("h264", 1920));
+
+    let method =
+        PlayerSetAudioStream::language(1, &properties.audiostreams.unwrap(), "fre").unwrap();
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"playerid": 1, "stream": 1})
+    );
+    let method = PlayerSetSubtitle::language(1, &properties.subtitles.unwrap(), "fre").unwrap();
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"playerid": 1, "subtitle": 0, "enable": true})
+    );
+    let method = PlayerSetSubtitle {
+        id: 1,
+        subtitle: PlayerSubtitle::State(PlayerSubtitleState::Off),
+        enable: false,
+    };
+    assert_eq!(serde_json::to_value(method).unwrap()["subtitle"], "off");
+
+    let method = PlayerSetViewMode {
+        viewmode: PlayerViewMode::Custom(PlayerCustomViewMode {
+            zoom: Some(PlayerViewModeAdjust::Step(PlayerViewModeStep::Increase)),
+            pixelratio: Some(PlayerViewModeAdjust::Value(1.0)),
+            ..Default::default()
+        }),
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"viewmode": {"zoom": "increase", "pixelratio": 1.0}})
+    );
+    assert_eq!(
+        serde_json::to_value(PlayerSetViewMode {
+            viewmode: PlayerViewMode::Mode(ViewMode::Stretch16x9Nonlin)
+        })
+        .unwrap(),
+        serde_json::json!({"viewmode": "stretch16x9nonlin"})
+    );
+}
+
+#[test]
+fn test_files_download() {
+    let response: FilesPrepareDownloadResponse = serde_json::from_value(serde_json::json!({
+        "details": {"path": "vfs/%2fmusic%2fsong.flac"},
+        "mode": "redirect",
+        "protocol": "http",
+    }))
+    .unwrap();
+    let endpoint = reqwest::Url::parse("http://kodi:8080/jsonrpc").unwrap();
+    assert_eq!(
+        response.url(&endpoint).unwrap().as_str(),
+        "http://kodi:8080/vfs/%2fmusic%2fsong.flac"
+    );
+
+    let method = FilesSetFileDetails {
+        file: "/movies/movie.mkv".to_owned(),
+        playcount: Some(1),
+        ..Default::default()
+    };
+    assert_eq!(
+        serde_json::to_value(method).unwrap(),
+        serde_json::json!({"file": "/movies/movie.mkv", "media": "video", "playcount": 1})
+    );
+}
@@ -0,0 +1,220 @@
+use crate::KodiTransportError;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tracing::{event, Level};
+
+/// Number of notifications kept for subscribers that are lagging behind
+const NOTIFICATIONS_CAPACITY: usize = 64;
+
+/// Requests waiting for a response over a persistent connection, indexed by their id,
+/// and subscribers to the notifications received over it.
+pub(crate) struct Multiplexer {
+    /// Set to `None` once the connection is closed so that new requests fail immediately.
+    pending: Mutex<Option<HashMap<usize, oneshot::Sender<String>>>>,
+    /// Set to `None` once the connection is closed so that subscribers are notified.
+    notifications: Mutex<Option<broadcast::Sender<String>>>,
+}
+
+impl Multiplexer {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: Mutex::new(Some(HashMap::new())),
+            notifications: Mutex::new(Some(broadcast::channel(NOTIFICATIONS_CAPACITY).0)),
+        }
+    }
+
+    fn register(&self, id: usize) -> Result<oneshot::Receiver<String>, KodiTransportError> {
+        let (sender, receiver) = oneshot::channel();
+        match &mut *self.pending.lock().unwrap() {
+            Some(pending) => pending.insert(id, sender),
+            None => return Err(KodiTransportError::Writing(connection_closed().into())),
+        };
+        Ok(receiver)
+    }
+
+    /// Forgets about a request which is not waiting for its response anymore
+    fn deregister(&self, id: usize) {
+        if let Some(pending) = &mut *self.pending.lock().unwrap() {
+            pending.remove(&id);
+        }
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<String> {
+        match &*self.notifications.lock().unwrap() {
+            Some(notifications) => notifications.subscribe(),
+            // The sender is dropped right away so the receiver is closed
+            None => broadcast::channel(1).1,
+        }
+    }
+
+    /// Routes a message received from Kodi to the request waiting for it,
+    /// or to the subscribers if it is a notification.
+    ///
+    /// The response to a batch is routed to the request with the lowest id in it. An error
+    /// without id, which Kodi sends when it could not read a request, is routed to every
+    /// request since the one at fault cannot be identified.
+    pub(crate) fn deliver(&self, text: &str) {
+        #[derive(Deserialize)]
+        struct Header {
+            id: Option<usize>,
+            method: Option<String>,
+            error: Option<serde::de::IgnoredAny>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Message {
+            Single(Header),
+            Batch(Vec<Header>),
+        }
+
+        event!(Level::DEBUG, "<- {text}", text = text);
+        let header = match serde_json::from_str::<Message>(text) {
+            Ok(Message::Single(header)) => header,
+            Ok(Message::Batch(headers)) => Header {
+                id: headers.iter().filter_map(|header| header.id).min(),
+                method: None,
+                error: None,
+            },
+            Err(err) => {
+                event!(Level::WARN, "Ignoring unexpected message: {}", err);
+                return;
+            }
+        };
+        match header {
+            Header { id: Some(id), .. } => {
+                let sender = self
+                    .pending
+                    .lock()
+                    .unwrap()
+                    .as_mut()
+                    .and_then(|pending| pending.remove(&id));
+                match sender {
+                    // The receiver might have been dropped if the request was cancelled
+                    Some(sender) => {
+                        let _ = sender.send(text.to_owned());
+                    }
+                    None => event!(Level::WARN, "Received response for unknown request {}", id),
+                }
+            }
+            Header {
+                id: None,
+                method: Some(_),
+                ..
+            } => {
+                if let Some(notifications) = &*self.notifications.lock().unwrap() {
+                    // Sending only fails when nobody has subscribed
+                    let _ = notifications.send(text.to_owned());
+                }
+            }
+            Header {
+                id: None,
+                error: Some(_),
+                ..
+            } => {
+                let senders = self
+                    .pending
+                    .lock()
+                    .unwrap()
+                    .as_mut()
+                    .map(std::mem::take)
+                    .unwrap_or_default();
+                event!(
+                    Level::WARN,
+                    "Received error without id, failing {} pending requests",
+                    senders.len()
+                );
+                for (_, sender) in senders {
+                    let _ = sender.send(text.to_owned());
+                }
+            }
+            Header { id: None, .. } => event!(Level::DEBUG, "Ignoring message without id"),
+        }
+    }
+
+    /// Fails every request still waiting for a response, as well as any future one,
+    /// and ends every subscription.
+    pub(crate) fn close(&self) {
+        self.pending.lock().unwrap().take();
+        self.notifications.lock().unwrap().take();
+    }
+}
+
+/// Sending side of a persistent connection whose I/O is driven by a background task.
+pub(crate) struct Connection {
+    requests: mpsc::UnboundedSender<String>,
+    multiplexer: Arc<Multiplexer>,
+}
+
+impl Connection {
+    /// Returns the connection along with the receiving end of the requests to be written and
+    /// the multiplexer responses must be delivered to, which both belong to the background task.
+    pub(crate) fn new() -> (Self, mpsc::UnboundedReceiver<String>, Arc<Multiplexer>) {
+        let (requests, receiver) = mpsc::unbounded_channel();
+        let multiplexer = Arc::new(Multiplexer::new());
+        (
+            Self {
+                requests,
+                multiplexer: multiplexer.clone(),
+            },
+            receiver,
+            multiplexer,
+        )
+    }
+
+    pub(crate) async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+        let receiver = self.multiplexer.register(id)?;
+        // Deregisters the request if it times out or is cancelled before its response arrives
+        let _pending = Pending {
+            multiplexer: &self.multiplexer,
+            id,
+        };
+        if self.requests.send(body).is_err() {
+            return Err(KodiTransportError::Writing(connection_closed().into()));
+        }
+        receiver
+            .await
+            .map_err(|_| KodiTransportError::Reading(connection_closed().into()))
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.multiplexer.subscribe()
+    }
+}
+
+/// Request waiting for its response, deregistered once dropped
+struct Pending<'a> {
+    multiplexer: &'a Multiplexer,
+    id: usize,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        self.multiplexer.deregister(self.id);
+    }
+}
+
+fn connection_closed() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::ConnectionAborted,
+        "connection to kodi closed",
+    )
+}
+
+#[tokio::test]
+async fn test_cancelled_request() {
+    let (connection, _requests, multiplexer) = Connection::new();
+    let sent = connection.send(42, "{}".to_owned());
+    let timeout = std::time::Duration::from_millis(1);
+    assert!(tokio::time::timeout(timeout, sent).await.is_err());
+    assert!(multiplexer
+        .pending
+        .lock()
+        .unwrap()
+        .as_ref()
+        .unwrap()
+        .is_empty());
+}
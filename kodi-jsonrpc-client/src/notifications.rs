@@ -0,0 +1,230 @@
+use tracing::{event, Level};
+
+macro_rules! define_notifications {
+    ($( $( #[$attr:meta] )* $root:ident . $notification:ident $( ( $data_ty:ty ) )? ),* $(,)?) => {
+        paste::paste! {
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum KodiNotification {
+                $( $( #[$attr] )* [<$root $notification>] $( ($data_ty) )?, )*
+                /// Any other notification, or a notification whose data could not be parsed
+                Other {
+                    method: String,
+                    data: serde_json::Value,
+                },
+            }
+
+            impl KodiNotification {
+                fn from_data(method: &str, data: serde_json::Value) -> Result<Self, serde_json::Error> {
+                    match method {
+                        $(
+                            std::concat!(std::stringify!($root), ".", std::stringify!($notification)) => {
+                                Ok(Self::[<$root $notification>] $( (serde_json::from_value::<$data_ty>(data)?) )?)
+                            }
+                        )*
+                        _ => Ok(Self::Other {
+                            method: method.to_owned(),
+                            data,
+                        }),
+                    }
+                }
+            }
+        }
+    };
+}
+
+define_notifications!(
+    #[doc = "The volume of the application has changed"]
+    Application.OnVolumeChanged(ApplicationOnVolumeChangedData),
+    #[doc = "A song has been removed from the audio library"]
+    AudioLibrary.OnRemove(AudioLibraryOnRemoveData),
+    #[doc = "An audio library scan has finished"]
+    AudioLibrary.OnScanFinished,
+    #[doc = "An audio library scan has started"]
+    AudioLibrary.OnScanStarted,
+    #[doc = "A song has been updated in the audio library"]
+    AudioLibrary.OnUpdate(AudioLibraryOnUpdateData),
+    #[doc = "Playback of a media item has been paused"]
+    Player.OnPause(crate::types::player::notifications::Data),
+    #[doc = "Playback of a media item has been started or the playback speed has changed"]
+    Player.OnPlay(crate::types::player::notifications::Data),
+    #[doc = "A property of the playing items has changed"]
+    Player.OnPropertyChanged(PlayerOnPropertyChangedData),
+    #[doc = "The playback position has been changed"]
+    Player.OnSeek(PlayerOnSeekData),
+    #[doc = "Playback of a media item has been stopped"]
+    Player.OnStop(PlayerOnStopData),
+    #[doc = "A playlist item has been added"]
+    Playlist.OnAdd(PlaylistOnAddData),
+    #[doc = "A playlist has been cleared"]
+    Playlist.OnClear(PlaylistOnClearData),
+    #[doc = "A playlist item has been removed"]
+    Playlist.OnRemove(PlaylistOnRemoveData),
+    #[doc = "Kodi will be closed"]
+    System.OnQuit(SystemOnQuitData),
+);
+
+impl std::str::FromStr for KodiNotification {
+    type Err = serde_json::Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        #[derive(serde::Deserialize)]
+        struct Params {
+            #[serde(default)]
+            data: serde_json::Value,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Notification {
+            method: String,
+            params: Params,
+        }
+
+        let notification: Notification = serde_json::from_str(text)?;
+        let method = notification.method;
+        let data = notification.params.data;
+        match Self::from_data(&method, data.clone()) {
+            Ok(notification) => Ok(notification),
+            Err(err) => {
+                event!(Level::WARN, "Could not parse data of {}: {}", method, err);
+                Ok(Self::Other { method, data })
+            }
+        }
+    }
+}
+
+// Application notifications
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct ApplicationOnVolumeChangedData {
+    pub muted: bool,
+    pub volume: u8,
+}
+
+// AudioLibrary notifications
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct AudioLibraryOnRemoveData {
+    pub id: usize,
+    #[serde(rename = "type")]
+    pub kind: crate::types::notifications::library::audio::Type,
+    pub transaction: Option<bool>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct AudioLibraryOnUpdateData {
+    pub id: usize,
+    #[serde(rename = "type")]
+    pub kind: crate::types::notifications::library::audio::Type,
+    pub added: Option<bool>,
+    pub transaction: Option<bool>,
+}
+
+// Player notifications
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize)]
+pub struct PlayerOnPropertyChangedData {
+    pub player: crate::types::player::notifications::Player,
+    #[serde(default)]
+    pub property: crate::types::player::property::Value,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct PlayerOnSeekData {
+    #[serde(default)]
+    pub item: crate::types::notifications::Item,
+    pub player: crate::types::player::notifications::Seek,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct PlayerOnStopData {
+    /// Whether the player has reached the end of the playable item(s) or not
+    pub end: bool,
+    #[serde(default)]
+    pub item: crate::types::notifications::Item,
+}
+
+// Playlist notifications
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct PlaylistOnAddData {
+    #[serde(default)]
+    pub item: crate::types::notifications::Item,
+    #[serde(rename = "playlistid")]
+    pub id: u8,
+    #[serde(default, deserialize_with = "crate::deserialize_opt_usize")]
+    pub position: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct PlaylistOnClearData {
+    #[serde(rename = "playlistid")]
+    pub id: u8,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct PlaylistOnRemoveData {
+    #[serde(rename = "playlistid")]
+    pub id: u8,
+    #[serde(default, deserialize_with = "crate::deserialize_opt_usize")]
+    pub position: Option<usize>,
+}
+
+// System notifications
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct SystemOnQuitData {
+    pub exitcode: usize,
+}
+
+#[tokio::test]
+async fn test_notifications() {
+    use crate::tcp::TcpTransport;
+    use crate::types::notifications::ItemType;
+    use crate::KodiClient;
+    use futures::StreamExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let client = KodiClient::with_transport(TcpTransport::connect(addr).await.unwrap());
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut notifications = client.notifications();
+
+    socket
+        .write_all(
+            concat!(
+                r#"{"jsonrpc":"2.0","method":"Player.OnPause","params":{"data":{"item":{"id":42,"type":"song"},"player":{"playerid":0,"speed":0}},"sender":"xbmc"}}"#,
+                r#"{"jsonrpc":"2.0","method":"AudioLibrary.OnScanStarted","params":{"data":null,"sender":"xbmc"}}"#,
+                r#"{"jsonrpc":"2.0","method":"Playlist.OnClear","params":{"data":{"playlistid":"oops"},"sender":"xbmc"}}"#,
+                r#"{"jsonrpc":"2.0","method":"GUI.OnScreensaverActivated","params":{"data":null,"sender":"xbmc"}}"#,
+            )
+            .as_bytes(),
+        )
+        .await
+        .unwrap();
+    drop(socket);
+
+    match notifications.next().await.unwrap() {
+        KodiNotification::PlayerOnPause(data) => {
+            assert_eq!(data.item.kind, ItemType::Song);
+            assert_eq!(data.item.id, Some(42));
+            assert_eq!(data.player.id, 0);
+        }
+        notification => panic!("unexpected notification {:?}", notification),
+    }
+    assert_eq!(
+        notifications.next().await,
+        Some(KodiNotification::AudioLibraryOnScanStarted)
+    );
+    assert!(matches!(
+        notifications.next().await,
+        Some(KodiNotification::Other { method, .. }) if method == "Playlist.OnClear"
+    ));
+    assert!(matches!(
+        notifications.next().await,
+        Some(KodiNotification::Other { method, .. }) if method == "GUI.OnScreensaverActivated"
+    ));
+    assert_eq!(notifications.next().await, None);
+}
@@ -0,0 +1,145 @@
+use crate::types::list::{Limits, LimitsReturned};
+use crate::{KodiClient, KodiError, KodiMethod, KodiTransport};
+use futures::{Stream, StreamExt};
+
+/// List method whose items can be fetched page by page through its `limits`
+pub trait KodiPagedMethod: KodiMethod {
+    type Item;
+
+    fn set_limits(&mut self, limits: Limits);
+    /// Splits a page into its items and the limits Kodi returned for it
+    fn into_page(response: Self::Response) -> (Vec<Self::Item>, LimitsReturned);
+}
+
+macro_rules! impl_paged_method {
+    ($( $method:ident => $items:ident : $item:ty ),* $(,)?) => {
+        $(
+            impl KodiPagedMethod for crate::methods::$method {
+                type Item = $item;
+
+                fn set_limits(&mut self, limits: Limits) {
+                    self.limits = Some(limits);
+                }
+
+                fn into_page(response: Self::Response) -> (Vec<Self::Item>, LimitsReturned) {
+                    (response.$items, response.limits)
+                }
+            }
+        )*
+    };
+}
+
+impl_paged_method!(
+    AddonsGetAddons => addons: crate::types::addon::details::Addon,
+    AudioLibraryGetAlbums => albums: crate::types::audio::details::Album,
+    AudioLibraryGetArtists => artists: crate::types::audio::details::Artist,
+    AudioLibraryGetGenres => genres: crate::types::library::details::Genre,
+    AudioLibraryGetRecentlyAddedAlbums => albums: crate::types::audio::details::Album,
+    AudioLibraryGetRecentlyAddedSongs => songs: crate::types::audio::details::Song,
+    AudioLibraryGetRecentlyPlayedAlbums => albums: crate::types::audio::details::Album,
+    AudioLibraryGetRecentlyPlayedSongs => songs: crate::types::audio::details::Song,
+    AudioLibraryGetRoles => roles: crate::types::audio::details::Role,
+    AudioLibraryGetSongs => songs: crate::types::audio::details::Song,
+    AudioLibraryGetSources => sources: crate::types::library::details::Source,
+    FilesGetDirectory => files: crate::types::list::item::File,
+    PVRGetBroadcasts => broadcasts: crate::types::pvr::details::Broadcast,
+    PVRGetChannelGroups => channelgroups: crate::types::pvr::details::ChannelGroup,
+    PVRGetChannels => channels: crate::types::pvr::details::Channel,
+    PVRGetRecordings => recordings: crate::types::pvr::details::Recording,
+    PVRGetTimers => timers: crate::types::pvr::details::Timer,
+    PlaylistGetItems => items: crate::types::list::item::All,
+    ProfilesGetProfiles => profiles: crate::types::profiles::details::Profile,
+    VideoLibraryGetEpisodes => episodes: crate::types::video::details::Episode,
+    VideoLibraryGetGenres => genres: crate::types::library::details::Genre,
+    VideoLibraryGetInProgressTVShows => tvshows: crate::types::video::details::TVShow,
+    VideoLibraryGetMovieSets => sets: crate::types::video::details::MovieSet,
+    VideoLibraryGetMovies => movies: crate::types::video::details::Movie,
+    VideoLibraryGetMusicVideos => musicvideos: crate::types::video::details::MusicVideo,
+    VideoLibraryGetRecentlyAddedEpisodes => episodes: crate::types::video::details::Episode,
+    VideoLibraryGetRecentlyAddedMovies => movies: crate::types::video::details::Movie,
+    VideoLibraryGetRecentlyAddedMusicVideos => musicvideos: crate::types::video::details::MusicVideo,
+    VideoLibraryGetSeasons => seasons: crate::types::video::details::Season,
+    VideoLibraryGetTVShows => tvshows: crate::types::video::details::TVShow,
+    VideoLibraryGetTags => tags: crate::types::library::details::Tag,
+);
+
+pub(crate) fn paginate<'a, T: KodiTransport, M: KodiPagedMethod + 'a>(
+    client: &'a KodiClient<T>,
+    method: M,
+    page_size: usize,
+) -> impl Stream<Item = Result<M::Item, KodiError>> + Unpin + 'a {
+    let pages = futures::stream::unfold((method, Some(0)), move |(mut method, start)| async move {
+        let start = start?;
+        method.set_limits(Limits {
+            start,
+            end: start + page_size,
+        });
+        match client.send_method_ref(&method).await {
+            Ok(response) => {
+                let (items, limits) = M::into_page(response);
+                // An empty page also ends the stream, should the total change while paging
+                let next = Some(limits.end).filter(|end| *end < limits.total && !items.is_empty());
+                Some((Ok(items), (method, next)))
+            }
+            Err(error) => Some((Err(error), (method, None))),
+        }
+    });
+    Box::pin(pages.flat_map(|page| {
+        let items: Vec<_> = match page {
+            Ok(items) => items.into_iter().map(Ok).collect(),
+            Err(error) => vec![Err(error)],
+        };
+        futures::stream::iter(items)
+    }))
+}
+
+#[tokio::test]
+async fn test_paginate() {
+    use crate::methods::AudioLibraryGetGenres;
+    use crate::KodiTransportError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Lists 5 genres, recording the limits of each request
+    #[derive(Default)]
+    struct GenresTransport {
+        limits: Mutex<Vec<(u64, u64)>>,
+    }
+
+    #[async_trait]
+    impl KodiTransport for GenresTransport {
+        async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            let limits = &request["params"]["limits"];
+            let (start, end) = (
+                limits["start"].as_u64().unwrap(),
+                limits["end"].as_u64().unwrap(),
+            );
+            self.limits.lock().unwrap().push((start, end));
+            let end = end.min(5);
+            let genres: Vec<_> = (start..end)
+                .map(
+                    |genreid| serde_json::json!({"genreid": genreid, "label": genreid.to_string()}),
+                )
+                .collect();
+            Ok(serde_json::json!({
+                "id": id,
+                "jsonrpc": "2.0",
+                "result": {"genres": genres, "limits": {"start": start, "end": end, "total": 5}},
+            })
+            .to_string())
+        }
+    }
+
+    let client = KodiClient::with_transport(GenresTransport::default());
+    let genres: Vec<_> = client
+        .paginate(AudioLibraryGetGenres::all_properties(), 2)
+        .map(|genre| genre.unwrap().genreid)
+        .collect()
+        .await;
+    assert_eq!(genres, vec![0, 1, 2, 3, 4]);
+    assert_eq!(
+        *client.transport().limits.lock().unwrap(),
+        vec![(0, 2), (2, 4), (4, 6)]
+    );
+}
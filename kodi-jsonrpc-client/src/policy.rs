@@ -0,0 +1,162 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long requests are waited for and how often they are sent again when Kodi does not
+/// answer them.
+///
+/// Only idempotent methods, see [`crate::KodiMethod::IDEMPOTENT`], are sent again.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KodiPolicy {
+    /// Time after which a request is abandoned, `None` to wait for as long as it takes
+    pub timeout: Option<Duration>,
+    /// Number of times a request is sent again after a timeout or a transport error
+    pub retries: usize,
+    /// Delay before the first retry, doubled for each of the next ones
+    pub backoff: Duration,
+    /// Upper bound of the delay between two retries
+    pub max_backoff: Duration,
+}
+
+impl Default for KodiPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            retries: 0,
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl KodiPolicy {
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn with_retries(mut self, retries: usize, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Delay before sending a request again after `attempt` failed attempts
+    pub(crate) fn backoff(&self, attempt: usize) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1) as u32);
+        self.backoff
+            .checked_mul(factor)
+            .map_or(self.max_backoff, |backoff| backoff.min(self.max_backoff))
+    }
+}
+
+/// State of a [`CircuitBreaker`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Requests are sent as usual
+    Closed,
+    /// Kodi is deemed unreachable and requests fail right away, until `retry_in` has elapsed
+    Open { retry_in: Duration },
+    /// A request is being sent to check whether Kodi is reachable again
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum Breaker {
+    Closed { failures: usize },
+    Open { since: Instant },
+    HalfOpen { since: Instant },
+}
+
+/// Fails requests fast while Kodi is unreachable, instead of having each of them wait for its
+/// own timeout.
+///
+/// The circuit opens after a number of requests in a row could not be exchanged with Kodi.
+/// Once the cooldown has elapsed a single request is let through, closing the circuit again
+/// if it succeeds. Errors returned by Kodi itself mean it is reachable and are not failures.
+///
+/// A breaker can be shared by several clients talking to the same Kodi.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: usize,
+    cooldown: Duration,
+    state: Mutex<Breaker>,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: usize, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: Mutex::new(Breaker::Closed { failures: 0 }),
+        }
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            Breaker::Closed { .. } => CircuitState::Closed,
+            Breaker::Open { since } => match self.cooldown.checked_sub(since.elapsed()) {
+                Some(retry_in) if retry_in > Duration::from_secs(0) => {
+                    CircuitState::Open { retry_in }
+                }
+                _ => CircuitState::HalfOpen,
+            },
+            Breaker::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Checks whether a request can be sent, returning the time left before it can otherwise
+    pub(crate) fn acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            Breaker::Closed { .. } => Ok(()),
+            // Another request is let through if the one checking Kodi got cancelled
+            Breaker::Open { since } | Breaker::HalfOpen { since } => {
+                match self.cooldown.checked_sub(since.elapsed()) {
+                    Some(retry_in) if retry_in > Duration::from_secs(0) => Err(retry_in),
+                    _ => {
+                        *state = Breaker::HalfOpen {
+                            since: Instant::now(),
+                        };
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Records whether a request could be exchanged with Kodi
+    pub(crate) fn record(&self, success: bool) {
+        let mut state = self.state.lock().unwrap();
+        *state = match *state {
+            _ if success => Breaker::Closed { failures: 0 },
+            Breaker::Closed { failures } if failures + 1 < self.threshold => Breaker::Closed {
+                failures: failures + 1,
+            },
+            _ => Breaker::Open {
+                since: Instant::now(),
+            },
+        };
+    }
+}
+
+#[test]
+fn test_circuit_breaker() {
+    let breaker = CircuitBreaker::new(2, Duration::from_millis(50));
+    breaker.record(false);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+    breaker.record(false);
+    assert!(matches!(breaker.state(), CircuitState::Open { .. }));
+    assert!(breaker.acquire().is_err());
+
+    std::thread::sleep(Duration::from_millis(60));
+    assert_eq!(breaker.acquire(), Ok(()));
+    assert_eq!(breaker.state(), CircuitState::HalfOpen);
+    assert!(breaker.acquire().is_err());
+    breaker.record(true);
+    assert_eq!(breaker.state(), CircuitState::Closed);
+
+    let policy = KodiPolicy::default().with_retries(5, Duration::from_millis(100));
+    assert_eq!(policy.backoff(1), Duration::from_millis(100));
+    assert_eq!(policy.backoff(3), Duration::from_millis(400));
+    assert_eq!(policy.backoff(100), Duration::from_secs(5));
+}
@@ -0,0 +1,562 @@
+use crate::types::list::filter::rule::{Rule, Value};
+use crate::types::list::filter::{fields, Filter, Logical, Operators};
+use crate::types::list::{Limits, Sort, SortMethod, SortOrder};
+
+// Each library has a module of functions returning its filter fields, wrapped by the kind of
+// value they hold so that only the operators Kodi accepts for that kind can be used on them.
+
+fn rule<F>(field: F, operator: Operators, value: Value) -> Filter<Rule<F>> {
+    Filter::Rule(Rule {
+        operator,
+        value,
+        field,
+    })
+}
+
+/// Field holding text, such as a title or a genre
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Text<F>(F);
+
+impl<F> Text<F> {
+    pub fn contains(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Contains, value.into())
+    }
+
+    pub fn does_not_contain(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::DoesNotContain, value.into())
+    }
+
+    pub fn is(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Is, value.into())
+    }
+
+    /// Matches any of the values
+    pub fn is_any<I, S>(self, values: I) -> Filter<Rule<F>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let values: Vec<String> = values.into_iter().map(Into::into).collect();
+        rule(self.0, Operators::Is, values.into())
+    }
+
+    pub fn is_not(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::IsNot, value.into())
+    }
+
+    pub fn starts_with(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::StartsWith, value.into())
+    }
+
+    pub fn ends_with(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::EndsWith, value.into())
+    }
+}
+
+/// Field holding a number, such as a year or a play count
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Number<F>(F);
+
+impl<F> Number<F> {
+    pub fn is<N: Into<f64>>(self, value: N) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Is, number(value))
+    }
+
+    /// Matches a value given as text, which Kodi interprets as it sees fit, such as a value
+    /// typed by a user that may not be a number
+    pub fn is_text(self, value: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Is, value.into())
+    }
+
+    /// Matches any of the values
+    pub fn is_any<I, N>(self, values: I) -> Filter<Rule<F>>
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<f64>,
+    {
+        let values: Vec<String> = values
+            .into_iter()
+            .map(|value| value.into().to_string())
+            .collect();
+        rule(self.0, Operators::Is, values.into())
+    }
+
+    pub fn is_not<N: Into<f64>>(self, value: N) -> Filter<Rule<F>> {
+        rule(self.0, Operators::IsNot, number(value))
+    }
+
+    pub fn greater_than<N: Into<f64>>(self, value: N) -> Filter<Rule<F>> {
+        rule(self.0, Operators::GreaterThan, number(value))
+    }
+
+    pub fn less_than<N: Into<f64>>(self, value: N) -> Filter<Rule<F>> {
+        rule(self.0, Operators::LessThan, number(value))
+    }
+
+    /// Matches values from `low` to `high`, both included
+    pub fn between<N: Into<f64>>(self, low: N, high: N) -> Filter<Rule<F>> {
+        let values = vec![low.into().to_string(), high.into().to_string()];
+        rule(self.0, Operators::Between, values.into())
+    }
+}
+
+fn number<N: Into<f64>>(value: N) -> Value {
+    value.into().to_string().into()
+}
+
+/// Field holding a date, compared to dates such as `2020-01-31` or, for the `in_the_last`
+/// operators, to durations such as `2 weeks`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Date<F>(F);
+
+impl<F> Date<F> {
+    pub fn is(self, date: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Is, date.into())
+    }
+
+    pub fn is_not(self, date: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::IsNot, date.into())
+    }
+
+    pub fn after(self, date: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::After, date.into())
+    }
+
+    pub fn before(self, date: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Before, date.into())
+    }
+
+    pub fn in_the_last(self, duration: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::InTheLast, duration.into())
+    }
+
+    pub fn not_in_the_last(self, duration: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::NotInTheLast, duration.into())
+    }
+}
+
+/// Field holding a flag, such as whether an album is a compilation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Boolean<F>(F);
+
+impl<F> Boolean<F> {
+    pub fn is_true(self) -> Filter<Rule<F>> {
+        rule(self.0, Operators::True, Value::One(String::new()))
+    }
+
+    pub fn is_false(self) -> Filter<Rule<F>> {
+        rule(self.0, Operators::False, Value::One(String::new()))
+    }
+}
+
+/// Field matching the items of a smart playlist or virtual folder, given by its path
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Playlist<F>(F);
+
+impl<F> Playlist<F> {
+    pub fn is(self, path: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::Is, path.into())
+    }
+
+    pub fn is_not(self, path: &str) -> Filter<Rule<F>> {
+        rule(self.0, Operators::IsNot, path.into())
+    }
+}
+
+/// Filter matching the items matched by all the given filters
+pub fn all<F>(filters: Vec<Filter<Rule<F>>>) -> Filter<Rule<F>> {
+    Filter::Logical(Logical::And(filters))
+}
+
+/// Filter matching the items matched by any of the given filters
+pub fn any<F>(filters: Vec<Filter<Rule<F>>>) -> Filter<Rule<F>> {
+    Filter::Logical(Logical::Or(filters))
+}
+
+/// Filter, sort and limits of a library list method, for the filter fields `F`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query<F> {
+    pub filter: Option<Filter<Rule<F>>>,
+    pub sort: Option<Sort>,
+    pub limits: Option<Limits>,
+}
+
+impl<F> Default for Query<F> {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            sort: None,
+            limits: None,
+        }
+    }
+}
+
+impl<F> Query<F> {
+    /// Restricts the items to the ones matched by `filter`, along with the previous filters
+    pub fn filter(self, filter: Filter<Rule<F>>) -> Self {
+        self.and(filter)
+    }
+
+    pub fn and(mut self, filter: Filter<Rule<F>>) -> Self {
+        match &mut self.filter {
+            Some(current) => {
+                current.and(filter);
+            }
+            None => self.filter = Some(filter),
+        }
+        self
+    }
+
+    pub fn or(mut self, filter: Filter<Rule<F>>) -> Self {
+        match &mut self.filter {
+            Some(current) => {
+                current.or(filter);
+            }
+            None => self.filter = Some(filter),
+        }
+        self
+    }
+
+    /// Sorts the items in ascending order
+    pub fn sort_by(mut self, method: SortMethod) -> Self {
+        self.sort = Some(Sort {
+            ignorearticle: false,
+            method,
+            order: SortOrder::Ascending,
+            useartistsortname: false,
+        });
+        self
+    }
+
+    /// Sorts the items in descending order
+    pub fn sort_by_descending(self, method: SortMethod) -> Self {
+        let mut query = self.sort_by(method);
+        if let Some(sort) = &mut query.sort {
+            sort.order = SortOrder::Descending;
+        }
+        query
+    }
+
+    /// Ignores articles such as "The" when sorting, set after `sort_by`
+    pub fn ignore_article(mut self) -> Self {
+        if let Some(sort) = &mut self.sort {
+            sort.ignorearticle = true;
+        }
+        self
+    }
+
+    /// Sorts artists by their sort name rather than their name, set after `sort_by`
+    pub fn use_artist_sort_name(mut self) -> Self {
+        if let Some(sort) = &mut self.sort {
+            sort.useartistsortname = true;
+        }
+        self
+    }
+
+    pub fn limits<L: Into<Limits>>(mut self, limits: L) -> Self {
+        self.limits = Some(limits.into());
+        self
+    }
+
+    pub fn into_parts(self) -> (Option<Filter<Rule<F>>>, Option<Sort>, Option<Limits>) {
+        (self.filter, self.sort, self.limits)
+    }
+}
+
+macro_rules! define_fields {
+    ($( $library:ident : $fields:ident { $( $name:ident : $kind:ident = $field:ident ),* $(,)? } )*) => {
+        $(
+            pub fn $library() -> Query<fields::$fields> {
+                Query::default()
+            }
+
+            pub mod $library {
+                use crate::types::list::filter::fields::$fields;
+                $(
+                    pub fn $name() -> super::$kind<$fields> {
+                        super::$kind($fields::$field)
+                    }
+                )*
+            }
+        )*
+    };
+}
+
+define_fields!(
+    albums: Albums {
+        genre: Text = Genre,
+        source: Text = Source,
+        album: Text = Album,
+        artist: Text = Artist,
+        album_artist: Text = AlbumArtist,
+        year: Number = Year,
+        review: Text = Review,
+        themes: Text = Themes,
+        moods: Text = Moods,
+        styles: Text = Styles,
+        compilation: Boolean = Compilation,
+        kind: Text = Type,
+        label: Text = Label,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        play_count: Number = PlayCount,
+        last_played: Date = LastPlayed,
+        path: Text = Path,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    artists: Artists {
+        artist: Text = Artist,
+        source: Text = Source,
+        genre: Text = Genre,
+        moods: Text = Moods,
+        styles: Text = Styles,
+        instruments: Text = Instruments,
+        biography: Text = Biography,
+        artist_type: Text = ArtistType,
+        gender: Text = Gender,
+        disambiguation: Text = Disambiguation,
+        born: Text = Born,
+        band_formed: Text = BandFormed,
+        disbanded: Text = Disbanded,
+        died: Text = Died,
+        role: Text = Role,
+        path: Text = Path,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    songs: Songs {
+        genre: Text = Genre,
+        source: Text = Source,
+        album: Text = Album,
+        artist: Text = Artist,
+        album_artist: Text = AlbumArtist,
+        title: Text = Title,
+        year: Number = Year,
+        time: Number = Time,
+        track_number: Number = TrackNumber,
+        filename: Text = Filename,
+        path: Text = Path,
+        play_count: Number = PlayCount,
+        last_played: Date = LastPlayed,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        comment: Text = Comment,
+        moods: Text = Moods,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    movies: Movies {
+        title: Text = Title,
+        original_title: Text = OriginalTitle,
+        plot: Text = Plot,
+        plot_outline: Text = PlotOutline,
+        tagline: Text = Tagline,
+        votes: Number = Votes,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        time: Number = Time,
+        writers: Text = Writers,
+        play_count: Number = PlayCount,
+        last_played: Date = LastPlayed,
+        in_progress: Boolean = InProgress,
+        genre: Text = Genre,
+        country: Text = Country,
+        year: Number = Year,
+        director: Text = Director,
+        actor: Text = Actor,
+        mpaa_rating: Text = MpaaRating,
+        top250: Number = Top250,
+        studio: Text = Studio,
+        has_trailer: Boolean = HasTrailer,
+        filename: Text = Filename,
+        path: Text = Path,
+        set: Text = Set,
+        tag: Text = Tag,
+        date_added: Date = DateAdded,
+        video_resolution: Number = VideoResolution,
+        audio_channels: Number = AudioChannels,
+        audio_count: Number = AudioCount,
+        subtitle_count: Number = SubtitleCount,
+        video_codec: Text = VideoCodec,
+        audio_codec: Text = AudioCodec,
+        audio_language: Text = AudioLanguage,
+        subtitle_language: Text = SubtitleLanguage,
+        video_aspect: Number = VideoAspect,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    tvshows: TVShows {
+        title: Text = Title,
+        original_title: Text = OriginalTitle,
+        plot: Text = Plot,
+        status: Text = Status,
+        votes: Number = Votes,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        year: Number = Year,
+        genre: Text = Genre,
+        director: Text = Director,
+        actor: Text = Actor,
+        num_episodes: Number = NumEpisodes,
+        num_watched: Number = NumWatched,
+        play_count: Number = PlayCount,
+        path: Text = Path,
+        studio: Text = Studio,
+        mpaa_rating: Text = MpaaRating,
+        date_added: Date = DateAdded,
+        last_played: Date = LastPlayed,
+        in_progress: Boolean = InProgress,
+        tag: Text = Tag,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    episodes: Episodes {
+        title: Text = Title,
+        tv_show: Text = TvShow,
+        original_title: Text = OriginalTitle,
+        plot: Text = Plot,
+        votes: Number = Votes,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        time: Number = Time,
+        writers: Text = Writers,
+        air_date: Date = AirDate,
+        play_count: Number = PlayCount,
+        last_played: Date = LastPlayed,
+        in_progress: Boolean = InProgress,
+        genre: Text = Genre,
+        year: Number = Year,
+        director: Text = Director,
+        actor: Text = Actor,
+        episode: Number = Episode,
+        season: Number = Season,
+        filename: Text = Filename,
+        path: Text = Path,
+        studio: Text = Studio,
+        mpaa_rating: Text = MpaaRating,
+        date_added: Date = DateAdded,
+        tag: Text = Tag,
+        video_resolution: Number = VideoResolution,
+        audio_channels: Number = AudioChannels,
+        audio_count: Number = AudioCount,
+        subtitle_count: Number = SubtitleCount,
+        video_codec: Text = VideoCodec,
+        audio_codec: Text = AudioCodec,
+        audio_language: Text = AudioLanguage,
+        subtitle_language: Text = SubtitleLanguage,
+        video_aspect: Number = VideoAspect,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    musicvideos: MusicVideos {
+        title: Text = Title,
+        genre: Text = Genre,
+        album: Text = Album,
+        year: Number = Year,
+        artist: Text = Artist,
+        filename: Text = Filename,
+        path: Text = Path,
+        play_count: Number = PlayCount,
+        last_played: Date = LastPlayed,
+        rating: Number = Rating,
+        user_rating: Number = UserRating,
+        time: Number = Time,
+        director: Text = Director,
+        studio: Text = Studio,
+        plot: Text = Plot,
+        tag: Text = Tag,
+        date_added: Date = DateAdded,
+        video_resolution: Number = VideoResolution,
+        audio_channels: Number = AudioChannels,
+        audio_count: Number = AudioCount,
+        subtitle_count: Number = SubtitleCount,
+        video_codec: Text = VideoCodec,
+        audio_codec: Text = AudioCodec,
+        audio_language: Text = AudioLanguage,
+        subtitle_language: Text = SubtitleLanguage,
+        video_aspect: Number = VideoAspect,
+        playlist: Playlist = Playlist,
+        virtual_folder: Playlist = VirtualFolder
+    }
+    textures: Textures {
+        texture_id: Number = TextureId,
+        url: Text = Url,
+        cached_url: Text = CachedUrl,
+        last_hash_check: Date = LastHashCheck,
+        image_hash: Text = ImageHash,
+        width: Number = Width,
+        height: Number = Height,
+        use_count: Number = UseCount,
+        last_used: Date = LastUsed
+    }
+);
+
+macro_rules! impl_with_query {
+    ($( $method:ident : $fields:ident ),* $(,)?) => {
+        $(
+            impl crate::methods::$method {
+                /// Replaces the filter, sort and limits of the method by the ones of `query`
+                pub fn with_query(mut self, query: Query<fields::$fields>) -> Self {
+                    let (filter, sort, limits) = query.into_parts();
+                    self.filter = filter.map(Into::into);
+                    self.sort = sort;
+                    self.limits = limits;
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_with_query!(
+    AudioLibraryGetAlbums: Albums,
+    AudioLibraryGetArtists: Artists,
+    AudioLibraryGetSongs: Songs,
+    VideoLibraryGetEpisodes: Episodes,
+    VideoLibraryGetMovies: Movies,
+    VideoLibraryGetMusicVideos: MusicVideos,
+    VideoLibraryGetTVShows: TVShows,
+);
+
+#[test]
+fn test_query() {
+    use crate::methods::AudioLibraryGetSongs;
+
+    let query = songs()
+        .filter(songs::artist().is("Radiohead"))
+        .and(songs::year().between(1990, 1999))
+        .and(any(vec![
+            songs::genre().starts_with("Rock"),
+            songs::last_played().in_the_last("2 weeks"),
+        ]))
+        .sort_by_descending(SortMethod::Title)
+        .limits(0..=50);
+    let method = AudioLibraryGetSongs::all_properties().with_query(query);
+    let value = serde_json::to_value(method).unwrap();
+    assert_eq!(
+        value["filter"],
+        serde_json::json!({"and": [
+            {"field": "artist", "operator": "is", "value": "Radiohead"},
+            {"field": "year", "operator": "between", "value": ["1990", "1999"]},
+            {"or": [
+                {"field": "genre", "operator": "startswith", "value": "Rock"},
+                {"field": "lastplayed", "operator": "inthelast", "value": "2 weeks"},
+            ]},
+        ]})
+    );
+    assert_eq!(value["sort"]["order"], "descending");
+    assert_eq!(value["limits"], serde_json::json!({"start": 0, "end": 50}));
+
+    let (filter, _, _) = songs().or(songs::year().is_text("199x")).into_parts();
+    assert_eq!(
+        serde_json::to_value(filter).unwrap(),
+        serde_json::json!({"field": "year", "operator": "is", "value": "199x"})
+    );
+
+    let (filter, _, _) = albums().or(albums::compilation().is_true()).into_parts();
+    assert_eq!(
+        serde_json::to_value(filter).unwrap(),
+        serde_json::json!({"field": "compilation", "operator": "true", "value": ""})
+    );
+}
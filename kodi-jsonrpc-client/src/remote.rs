@@ -0,0 +1,189 @@
+use crate::methods::{
+    InputBack, InputContextMenu, InputDown, InputExecuteAction, InputHome, InputInfo, InputLeft,
+    InputRight, InputSelect, InputSendText, InputShowCodec, InputShowOSD,
+    InputShowPlayerProcessInfo, InputUp,
+};
+use crate::types::input::Action;
+use crate::{KodiClient, KodiError, KodiTransport};
+
+/// Button of a remote control that has its own `Input` method
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Key {
+    Up,
+    Down,
+    Left,
+    Right,
+    Select,
+    Back,
+    Home,
+    ContextMenu,
+    Info,
+    ShowOSD,
+    ShowCodec,
+    ShowPlayerProcessInfo,
+}
+
+/// Remote control driving the Kodi GUI through the `Input` methods.
+///
+/// Each call may send several methods in a row, one after the other, and stops at the first
+/// one that fails.
+pub struct RemoteControl<'a, T: KodiTransport> {
+    client: &'a KodiClient<T>,
+}
+
+impl<'a, T: KodiTransport> RemoteControl<'a, T> {
+    pub(crate) fn new(client: &'a KodiClient<T>) -> Self {
+        Self { client }
+    }
+
+    pub async fn press(&self, key: Key) -> Result<(), KodiError> {
+        let client = self.client;
+        match key {
+            Key::Up => client.send_method(InputUp {}).await?,
+            Key::Down => client.send_method(InputDown {}).await?,
+            Key::Left => client.send_method(InputLeft {}).await?,
+            Key::Right => client.send_method(InputRight {}).await?,
+            Key::Select => client.send_method(InputSelect {}).await?,
+            Key::Back => client.send_method(InputBack {}).await?,
+            Key::Home => client.send_method(InputHome {}).await?,
+            Key::ContextMenu => client.send_method(InputContextMenu {}).await?,
+            Key::Info => client.send_method(InputInfo {}).await?,
+            Key::ShowOSD => client.send_method(InputShowOSD {}).await?,
+            Key::ShowCodec => client.send_method(InputShowCodec {}).await?,
+            Key::ShowPlayerProcessInfo => client.send_method(InputShowPlayerProcessInfo {}).await?,
+        };
+        Ok(())
+    }
+
+    /// Presses the same key several times, such as to move down a list
+    pub async fn press_times(&self, key: Key, times: usize) -> Result<(), KodiError> {
+        for _ in 0..times {
+            self.press(key).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn execute(&self, action: Action) -> Result<(), KodiError> {
+        self.client
+            .send_method(InputExecuteAction { action })
+            .await?;
+        Ok(())
+    }
+
+    /// Executes the same action several times, such as to skip several items
+    pub async fn execute_times(&self, action: Action, times: usize) -> Result<(), KodiError> {
+        for _ in 0..times {
+            self.execute(action).await?;
+        }
+        Ok(())
+    }
+
+    /// Types text in the input dialog currently open, closing it if `submit` is true
+    pub async fn type_text(&self, text: &str, submit: bool) -> Result<(), KodiError> {
+        self.client
+            .send_method(InputSendText {
+                text: text.to_owned(),
+                done: submit,
+            })
+            .await?;
+        Ok(())
+    }
+
+    /// Enters a number digit by digit, as done on a keypad to switch channels
+    pub async fn enter_number(&self, number: u32) -> Result<(), KodiError> {
+        const DIGITS: [Action; 10] = [
+            Action::Number0,
+            Action::Number1,
+            Action::Number2,
+            Action::Number3,
+            Action::Number4,
+            Action::Number5,
+            Action::Number6,
+            Action::Number7,
+            Action::Number8,
+            Action::Number9,
+        ];
+        for digit in number.to_string().bytes() {
+            self.execute(DIGITS[(digit - b'0') as usize]).await?;
+        }
+        Ok(())
+    }
+
+    /// Changes the volume by a number of steps, lowering it if `steps` is negative
+    pub async fn change_volume(&self, steps: i32) -> Result<(), KodiError> {
+        let action = if steps < 0 {
+            Action::VolumeDown
+        } else {
+            Action::VolumeUp
+        };
+        self.execute_times(action, steps.unsigned_abs() as usize)
+            .await
+    }
+
+    /// Skips a number of items in the playlist, going back if `items` is negative
+    pub async fn skip(&self, items: i32) -> Result<(), KodiError> {
+        let action = if items < 0 {
+            Action::SkipPrevious
+        } else {
+            Action::SkipNext
+        };
+        self.execute_times(action, items.unsigned_abs() as usize)
+            .await
+    }
+
+    /// Goes back to the home window then opens the item at `position` in the main menu
+    pub async fn open_from_home(&self, position: usize) -> Result<(), KodiError> {
+        self.press(Key::Home).await?;
+        self.press_times(Key::Down, position).await?;
+        self.press(Key::Select).await
+    }
+}
+
+#[tokio::test]
+async fn test_remote_control() {
+    use crate::KodiTransportError;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// Records the methods and parameters it receives
+    #[derive(Default)]
+    struct RecordingTransport {
+        requests: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl KodiTransport for RecordingTransport {
+        async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            self.requests.lock().unwrap().push(format!(
+                "{} {}",
+                request["method"].as_str().unwrap(),
+                request["params"]
+            ));
+            Ok(format!(r#"{{"id":{},"jsonrpc":"2.0","result":"OK"}}"#, id))
+        }
+    }
+
+    let client = KodiClient::with_transport(RecordingTransport::default());
+    let remote = client.remote();
+    remote.open_from_home(2).await.unwrap();
+    remote.enter_number(105).await.unwrap();
+    remote.change_volume(-2).await.unwrap();
+    remote.type_text("kodi", true).await.unwrap();
+
+    assert_eq!(
+        *client.transport().requests.lock().unwrap(),
+        vec![
+            "Input.Home {}",
+            "Input.Down {}",
+            "Input.Down {}",
+            "Input.Select {}",
+            r#"Input.ExecuteAction {"action":"number1"}"#,
+            r#"Input.ExecuteAction {"action":"number0"}"#,
+            r#"Input.ExecuteAction {"action":"number5"}"#,
+            r#"Input.ExecuteAction {"action":"volumedown"}"#,
+            r#"Input.ExecuteAction {"action":"volumedown"}"#,
+            r#"Input.SendText {"done":true,"text":"kodi"}"#,
+        ]
+    );
+}
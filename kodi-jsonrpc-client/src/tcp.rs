@@ -0,0 +1,259 @@
+use crate::multiplex::{Connection, Multiplexer};
+use crate::{KodiNotificationTransport, KodiTransport, KodiTransportError};
+use async_trait::async_trait;
+use std::io;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, ToSocketAddrs};
+use tokio::sync::{broadcast, mpsc};
+use tracing::{event, Level};
+
+/// Persistent connection to Kodi raw JSON-RPC socket (port 9090 by default).
+///
+/// Kodi does not delimit messages on this socket: JSON objects are simply concatenated.
+/// Responses are matched to their request by `id`, so any number of requests can be in flight
+/// at the same time over a single connection.
+pub struct TcpTransport {
+    connection: Connection,
+}
+
+impl TcpTransport {
+    pub async fn connect<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr).await?;
+        stream.set_nodelay(true)?;
+        let (connection, requests, multiplexer) = Connection::new();
+        tokio::spawn(run(stream, requests, multiplexer));
+        Ok(Self { connection })
+    }
+}
+
+#[async_trait]
+impl KodiTransport for TcpTransport {
+    async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+        self.connection.send(id, body).await
+    }
+}
+
+impl KodiNotificationTransport for TcpTransport {
+    fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.connection.subscribe()
+    }
+}
+
+async fn run(
+    mut stream: TcpStream,
+    mut requests: mpsc::UnboundedReceiver<String>,
+    multiplexer: Arc<Multiplexer>,
+) {
+    let mut splitter = Splitter::default();
+    loop {
+        tokio::select! {
+            request = requests.recv() => match request {
+                Some(body) => {
+                    event!(Level::DEBUG, "-> {body}", body = body);
+                    if let Err(err) = stream.write_all(body.as_bytes()).await {
+                        event!(Level::ERROR, "Could not write to kodi: {}", err);
+                        break;
+                    }
+                }
+                None => break,
+            },
+            read = stream.read_buf(&mut splitter.buffer) => match read {
+                Ok(0) => {
+                    event!(Level::DEBUG, "kodi has closed the connection");
+                    break;
+                }
+                Ok(_) => {
+                    if let Err(err) = splitter.dispatch(&multiplexer) {
+                        event!(Level::ERROR, "Could not parse message from kodi: {}", err);
+                        break;
+                    }
+                }
+                Err(err) => {
+                    event!(Level::ERROR, "Could not read from kodi: {}", err);
+                    break;
+                }
+            },
+        }
+    }
+    multiplexer.close();
+}
+
+/// Splits the JSON values Kodi concatenates on the socket, each byte being scanned only once
+/// however many reads a value is received in.
+#[derive(Default)]
+struct Splitter {
+    buffer: Vec<u8>,
+    /// Number of bytes at the beginning of `buffer` that have already been scanned
+    scanned: usize,
+    /// Nesting level of objects and arrays at the end of the scanned bytes
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl Splitter {
+    /// Delivers every complete JSON value in the buffer and removes them from it.
+    ///
+    /// An incomplete trailing value is kept in the buffer until more data has been received.
+    fn dispatch(&mut self, multiplexer: &Multiplexer) -> io::Result<()> {
+        let mut consumed = 0;
+        for index in self.scanned..self.buffer.len() {
+            let byte = self.buffer[index];
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        let text = String::from_utf8_lossy(&self.buffer[consumed..=index]);
+                        multiplexer.deliver(text.trim());
+                        consumed = index + 1;
+                    }
+                }
+                _ if self.depth > 0 || byte.is_ascii_whitespace() => {}
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("unexpected byte {:?} between messages", byte as char),
+                    ))
+                }
+            }
+        }
+        self.buffer.drain(..consumed);
+        self.scanned = self.buffer.len();
+        Ok(())
+    }
+}
+
+#[test]
+fn test_splitter() {
+    let multiplexer = Multiplexer::new();
+    let mut notifications = multiplexer.subscribe();
+    let mut splitter = Splitter::default();
+    let messages = concat!(
+        r#"{"jsonrpc":"2.0","method":"Other.A","params":{"data":"}{\"]"}}"#,
+        "\n",
+        r#"{"jsonrpc":"2.0","method":"Other.B","params":{"data":[{}]}}"#,
+    );
+    for byte in messages.bytes() {
+        splitter.buffer.push(byte);
+        splitter.dispatch(&multiplexer).unwrap();
+    }
+    assert!(splitter.buffer.is_empty());
+    assert!(notifications.try_recv().unwrap().contains("Other.A"));
+    assert!(notifications.try_recv().unwrap().contains("Other.B"));
+
+    splitter.buffer.extend_from_slice(b"42");
+    assert!(splitter.dispatch(&multiplexer).is_err());
+}
+
+#[tokio::test]
+async fn test_tcp_transport() {
+    use crate::methods::{ApplicationSetVolume, JSONRPCVersion, JSONRPCVersionResponse};
+    use crate::KodiClient;
+    use tokio::net::TcpListener;
+
+    #[derive(serde::Deserialize)]
+    struct Request {
+        id: usize,
+        method: String,
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = Vec::new();
+        let mut requests = Vec::new();
+        while requests.len() < 2 {
+            socket.read_buf(&mut buffer).await.unwrap();
+            let mut stream = serde_json::Deserializer::from_slice(&buffer).into_iter::<Request>();
+            requests.clear();
+            while let Some(Ok(request)) = stream.next() {
+                requests.push(request);
+            }
+        }
+        // Answer in reverse order, as concatenated objects split across several writes
+        let mut responses = String::new();
+        for request in requests.iter().rev() {
+            let result = match request.method.as_str() {
+                "JSONRPC.Version" => r#"{"version":{"major":12,"minor":4,"patch":0}}"#,
+                "Application.SetVolume" => "42",
+                method => panic!("unexpected method {}", method),
+            };
+            responses.push_str(&format!(
+                r#"{{"id":{},"jsonrpc":"2.0","result":{}}}"#,
+                request.id, result
+            ));
+        }
+        let (first, second) = responses.split_at(responses.len() / 2 + 3);
+        socket.write_all(first.as_bytes()).await.unwrap();
+        socket.flush().await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        socket.write_all(second.as_bytes()).await.unwrap();
+    });
+
+    let client = KodiClient::with_transport(TcpTransport::connect(addr).await.unwrap());
+    let (version, volume) = tokio::join!(
+        client.send_method(JSONRPCVersion {}),
+        client.send_method(ApplicationSetVolume { volume: 42 }),
+    );
+    let JSONRPCVersionResponse::Version { major, minor, .. } = version.unwrap();
+    assert_eq!((major, minor), (12, 4));
+    assert_eq!(volume.unwrap(), 42);
+
+    server.await.unwrap();
+    assert!(client.send_method(JSONRPCVersion {}).await.is_err());
+}
+
+#[tokio::test]
+async fn test_tcp_transport_parse_error() {
+    use crate::methods::JSONRPCVersion;
+    use crate::{KodiClient, KodiError, KodiErrorCode};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buffer = Vec::new();
+        socket.read_buf(&mut buffer).await.unwrap();
+        socket
+            .write_all(
+                br#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"Parse error."},"id":null}"#,
+            )
+            .await
+            .unwrap();
+        // Keep the connection open so that the error is not caused by its closing
+        socket.read_buf(&mut buffer).await.unwrap();
+    });
+
+    let client = KodiClient::with_transport(TcpTransport::connect(addr).await.unwrap());
+    match client.send_method(JSONRPCVersion {}).await {
+        Err(KodiError::Jsonrpc {
+            method,
+            id: None,
+            code: KodiErrorCode::ParseError,
+            message,
+            ..
+        }) => {
+            assert_eq!(method, "JSONRPC.Version");
+            assert_eq!(message, "Parse error.");
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    drop(client);
+    server.await.unwrap();
+}
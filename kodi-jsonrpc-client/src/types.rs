@@ -1,3 +1,189 @@
+pub mod addon {
+    pub mod details {
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(untagged)]
+        pub enum Broken {
+            Flag(bool),
+            /// Reason why the addon is broken
+            Reason(String),
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Dependency {
+            pub addonid: String,
+            pub optional: bool,
+            pub version: String,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct ExtraInfo {
+            pub key: String,
+            pub value: String,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Addon {
+            pub label: String,
+            pub addonid: String,
+            pub author: Option<String>,
+            pub broken: Option<Broken>,
+            #[serde(default)]
+            pub dependencies: Vec<Dependency>,
+            pub description: Option<String>,
+            pub disclaimer: Option<String>,
+            pub enabled: Option<bool>,
+            #[serde(default)]
+            pub extrainfo: Vec<ExtraInfo>,
+            pub fanart: Option<String>,
+            pub installed: Option<bool>,
+            pub name: Option<String>,
+            pub path: Option<String>,
+            pub rating: Option<isize>,
+            pub summary: Option<String>,
+            pub thumbnail: Option<String>,
+            #[serde(rename = "type")]
+            pub kind: crate::types::addon::Type,
+            pub version: Option<String>,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Addon {
+            Name,
+            Version,
+            Summary,
+            Description,
+            Path,
+            Author,
+            Thumbnail,
+            Disclaimer,
+            FanArt,
+            Dependencies,
+            Broken,
+            ExtraInfo,
+            Rating,
+            Enabled,
+            Installed,
+        }
+    }
+
+    /// Content provided by a plugin or a script
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Content {
+        Unknown,
+        Video,
+        Audio,
+        Image,
+        Executable,
+    }
+
+    fn serialize_all<S>(serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("all")
+    }
+
+    /// Whether to only list the addons with a flag, such as enabled or installed, set or not
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(untagged)]
+    pub enum Selection {
+        #[serde(serialize_with = "crate::types::addon::serialize_all")]
+        All,
+        Only(bool),
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub enum Type {
+        #[serde(rename = "unknown")]
+        Unknown,
+        #[serde(rename = "xbmc.player.musicviz")]
+        MusicVisualization,
+        #[serde(rename = "xbmc.gui.skin")]
+        Skin,
+        #[serde(rename = "xbmc.pvrclient")]
+        PvrClient,
+        #[serde(rename = "kodi.inputstream")]
+        InputStream,
+        #[serde(rename = "kodi.gameclient")]
+        GameClient,
+        #[serde(rename = "kodi.peripheral")]
+        Peripheral,
+        #[serde(rename = "xbmc.python.script")]
+        Script,
+        #[serde(rename = "xbmc.python.weather")]
+        Weather,
+        #[serde(rename = "xbmc.subtitle.module")]
+        SubtitleModule,
+        #[serde(rename = "xbmc.python.lyrics")]
+        Lyrics,
+        #[serde(rename = "xbmc.metadata.scraper.albums")]
+        AlbumsScraper,
+        #[serde(rename = "xbmc.metadata.scraper.artists")]
+        ArtistsScraper,
+        #[serde(rename = "xbmc.metadata.scraper.movies")]
+        MoviesScraper,
+        #[serde(rename = "xbmc.metadata.scraper.musicvideos")]
+        MusicVideosScraper,
+        #[serde(rename = "xbmc.metadata.scraper.tvshows")]
+        TvShowsScraper,
+        #[serde(rename = "xbmc.ui.screensaver")]
+        Screensaver,
+        #[serde(rename = "xbmc.python.pluginsource")]
+        PluginSource,
+        #[serde(rename = "xbmc.addon.repository")]
+        Repository,
+        #[serde(rename = "xbmc.webinterface")]
+        WebInterface,
+        #[serde(rename = "xbmc.service")]
+        Service,
+        #[serde(rename = "kodi.audioencoder")]
+        AudioEncoder,
+        #[serde(rename = "kodi.context.item")]
+        ContextItem,
+        #[serde(rename = "kodi.audiodecoder")]
+        AudioDecoder,
+        #[serde(rename = "kodi.resource.images")]
+        ImagesResource,
+        #[serde(rename = "kodi.resource.language")]
+        LanguageResource,
+        #[serde(rename = "kodi.resource.uisounds")]
+        UiSoundsResource,
+        #[serde(rename = "kodi.resource.games")]
+        GamesResource,
+        #[serde(rename = "kodi.resource.font")]
+        FontResource,
+        #[serde(rename = "kodi.vfs")]
+        Vfs,
+        #[serde(rename = "kodi.imagedecoder")]
+        ImageDecoder,
+        #[serde(rename = "xbmc.metadata.scraper.library")]
+        ScraperLibrary,
+        #[serde(rename = "xbmc.python.library")]
+        PythonLibrary,
+        #[serde(rename = "xbmc.python.module")]
+        PythonModule,
+        #[serde(rename = "kodi.game.controller")]
+        GameController,
+        #[serde(rename = "xbmc.addon.video")]
+        Video,
+        #[serde(rename = "xbmc.addon.audio")]
+        Audio,
+        #[serde(rename = "xbmc.addon.image")]
+        Image,
+        #[serde(rename = "xbmc.addon.executable")]
+        Executable,
+        #[serde(rename = "kodi.addon.game")]
+        Game,
+    }
+}
+
 pub mod application {
     pub mod property {
         use enumset::EnumSetType;
@@ -52,6 +238,233 @@ pub mod application {
 }
 
 pub mod audio {
+    /// Change sets of the library items, where only the details that are set are sent to Kodi
+    pub mod changes {
+        macro_rules! define_changes {
+            ($( #[$attr:meta] )* $name:ident { $( $( #[$field_attr:meta] )* $field:ident : $ty:ty ),* }) => {
+                $( #[$attr] )*
+                #[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+                pub struct $name {
+                    $(
+                        $( #[$field_attr] )*
+                        #[serde(skip_serializing_if = "Option::is_none")]
+                        pub $field: Option<$ty>,
+                    )*
+                }
+
+                impl $name {
+                    $(
+                        pub fn $field(mut self, $field: $ty) -> Self {
+                            self.$field = Some($field);
+                            self
+                        }
+                    )*
+
+                    /// Whether nothing would be changed
+                    pub fn is_empty(&self) -> bool {
+                        true $( && self.$field.is_none() )*
+                    }
+                }
+            };
+        }
+
+        define_changes!(
+            Album {
+                title: String,
+                artist: Vec<String>,
+                description: String,
+                genre: Vec<String>,
+                theme: Vec<String>,
+                mood: Vec<String>,
+                style: Vec<String>,
+                #[serde(rename = "type")]
+                kind: String,
+                albumlabel: String,
+                rating: f64,
+                year: usize,
+                userrating: usize,
+                votes: usize,
+                musicbrainzalbumid: String,
+                musicbrainzreleasegroupid: String,
+                musicbrainzalbumartistid: Vec<String>,
+                sortartist: String,
+                displayartist: String,
+                compilation: bool,
+                art: crate::types::media::ArtworkSet
+            }
+        );
+
+        define_changes!(
+            Artist {
+                artist: String,
+                instrument: Vec<String>,
+                style: Vec<String>,
+                mood: Vec<String>,
+                born: String,
+                formed: String,
+                description: String,
+                genre: Vec<String>,
+                died: String,
+                disbanded: String,
+                yearsactive: Vec<String>,
+                musicbrainzartistid: Vec<String>,
+                sortname: String,
+                #[serde(rename = "type")]
+                kind: String,
+                gender: String,
+                disambiguation: String,
+                art: crate::types::media::ArtworkSet
+            }
+        );
+
+        define_changes!(
+            Song {
+                title: String,
+                artist: Vec<String>,
+                albumartist: Vec<String>,
+                genre: Vec<String>,
+                year: usize,
+                rating: f64,
+                album: String,
+                track: usize,
+                disc: usize,
+                duration: usize,
+                comment: String,
+                musicbrainztrackid: String,
+                musicbrainzartistid: Vec<String>,
+                musicbrainzalbumid: String,
+                musicbrainzalbumartistid: Vec<String>,
+                playcount: usize,
+                lastplayed: String,
+                userrating: usize,
+                votes: usize,
+                displayartist: String,
+                sortartist: String,
+                mood: Vec<String>,
+                art: crate::types::media::ArtworkSet,
+                dateadded: String
+            }
+        );
+
+        fn changed<T: Clone + PartialEq>(old: &Option<T>, new: &Option<T>) -> Option<T> {
+            if new != old {
+                new.clone()
+            } else {
+                None
+            }
+        }
+
+        /// Lists default to empty when they were not fetched, so empty lists are left unchanged
+        fn changed_list(old: &[String], new: &[String]) -> Option<Vec<String>> {
+            if !new.is_empty() && new != old {
+                Some(new.to_vec())
+            } else {
+                None
+            }
+        }
+
+        fn changed_art(
+            old: &Option<crate::types::media::Artwork>,
+            new: &Option<crate::types::media::Artwork>,
+        ) -> Option<crate::types::media::ArtworkSet> {
+            let new = new.as_ref()?;
+            let old = old.as_ref();
+            let art: crate::types::media::ArtworkSet = [
+                (
+                    "banner",
+                    &new.banner,
+                    old.and_then(|old| old.banner.as_ref()),
+                ),
+                (
+                    "fanart",
+                    &new.fanart,
+                    old.and_then(|old| old.fanart.as_ref()),
+                ),
+                (
+                    "poster",
+                    &new.poster,
+                    old.and_then(|old| old.poster.as_ref()),
+                ),
+                ("thumb", &new.thumb, old.and_then(|old| old.thumb.as_ref())),
+            ]
+            .iter()
+            .filter_map(|(kind, new, old)| match new {
+                Some(new) if Some(new) != *old => Some((kind.to_string(), Some(new.clone()))),
+                _ => None,
+            })
+            .collect();
+            if art.is_empty() {
+                None
+            } else {
+                Some(art)
+            }
+        }
+
+        impl Song {
+            /// Minimal change set turning the details of `old` into the ones of `new`.
+            ///
+            /// Details that are missing from `new` are left unchanged rather than cleared, and
+            /// details that cannot be changed, such as the ids, are ignored. Since lists such as
+            /// `genre` are empty when they were not fetched, empty lists are never diffed: tags
+            /// are cleared by setting an empty list explicitly.
+            pub fn diff(
+                old: &crate::types::audio::details::Song,
+                new: &crate::types::audio::details::Song,
+            ) -> Self {
+                Self {
+                    title: changed(&old.title, &new.title),
+                    artist: changed_list(&old.artist, &new.artist),
+                    albumartist: changed_list(&old.albumartist, &new.albumartist),
+                    genre: changed_list(&old.genre, &new.genre),
+                    year: changed(&old.year, &new.year),
+                    rating: changed(&old.rating, &new.rating),
+                    album: changed(&old.album, &new.album),
+                    track: changed(&old.track, &new.track),
+                    disc: changed(&old.disc, &new.disc),
+                    duration: changed(&old.duration, &new.duration),
+                    comment: changed(&old.comment, &new.comment),
+                    musicbrainztrackid: changed(&old.musicbrainztrackid, &new.musicbrainztrackid),
+                    musicbrainzartistid: changed(
+                        &old.musicbrainzartistid,
+                        &new.musicbrainzartistid,
+                    ),
+                    musicbrainzalbumartistid: changed(
+                        &old.musicbrainzalbumartistid,
+                        &new.musicbrainzalbumartistid,
+                    ),
+                    playcount: changed(&old.playcount, &new.playcount),
+                    lastplayed: changed(&old.lastplayed, &new.lastplayed),
+                    userrating: changed(&old.userrating, &new.userrating),
+                    votes: changed(&old.votes, &new.votes),
+                    displayartist: changed(&old.displayartist, &new.displayartist),
+                    sortartist: changed(&old.sortartist, &new.sortartist),
+                    mood: changed_list(&old.mood, &new.mood),
+                    art: changed_art(&old.art, &new.art),
+                    dateadded: changed(&old.dateadded, &new.dateadded),
+                    ..Self::default()
+                }
+            }
+
+            /// Keeps only the details held by a `kodi_music.xml` export, so that diffing a song
+            /// of [`crate::export::MusicLibrary`] against the live library does not report the
+            /// details missing from the export, such as the song artists or play counts.
+            pub fn exported_only(self) -> Self {
+                Self {
+                    title: self.title,
+                    albumartist: self.albumartist,
+                    year: self.year,
+                    album: self.album,
+                    track: self.track,
+                    disc: self.disc,
+                    duration: self.duration,
+                    musicbrainztrackid: self.musicbrainztrackid,
+                    musicbrainzalbumartistid: self.musicbrainzalbumartistid,
+                    ..Self::default()
+                }
+            }
+        }
+    }
+
     pub mod details {
         #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
         pub struct Album {
@@ -135,7 +548,7 @@ pub mod audio {
             pub yearsactive: Vec<String>,
         }
 
-        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
         pub struct Song {
             pub label: String,
             pub fanart: Option<String>,
@@ -150,7 +563,7 @@ pub mod audio {
             pub artistid: Vec<isize>,
             pub displayartist: Option<String>,
             pub musicbrainzalbumartistid: Option<Vec<String>>,
-            // pub rating: Option<f64>,
+            pub rating: Option<f64>,
             pub sortartist: Option<String>,
             pub title: Option<String>,
             pub userrating: Option<usize>,
@@ -186,6 +599,13 @@ pub mod audio {
             pub sourceid: Vec<isize>,
             pub track: Option<usize>,
         }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Role {
+            pub label: String,
+            pub roleid: usize,
+            pub title: Option<String>,
+        }
     }
 
     pub mod fields {
@@ -303,6 +723,92 @@ pub mod audio {
             Art,
             SourceId,
         }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Role {
+            Title,
+        }
+    }
+
+    /// Properties other than `missingartistid` and `librarylastupdated` require Kodi 19 or later
+    pub mod property {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Name {
+            MissingArtistId,
+            LibraryLastUpdated,
+            LibraryLastCleaned,
+            ArtistLinksUpdated,
+            SongsLastAdded,
+            AlbumsLastAdded,
+            ArtistsLastAdded,
+            GenresLastAdded,
+            SongsModified,
+            AlbumsModified,
+            ArtistsModified,
+        }
+
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Value {
+            #[serde(default, deserialize_with = "crate::deserialize_opt_usize")]
+            pub missingartistid: Option<usize>,
+            pub librarylastupdated: Option<String>,
+            pub librarylastcleaned: Option<String>,
+            pub artistlinksupdated: Option<String>,
+            pub songslastadded: Option<String>,
+            pub albumslastadded: Option<String>,
+            pub artistslastadded: Option<String>,
+            pub genreslastadded: Option<String>,
+            pub songsmodified: Option<String>,
+            pub albumsmodified: Option<String>,
+            pub artistsmodified: Option<String>,
+        }
+    }
+}
+
+pub mod favourite {
+    pub mod details {
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Favourite {
+            pub title: String,
+            #[serde(rename = "type")]
+            pub kind: crate::types::favourite::Type,
+            pub path: Option<String>,
+            pub thumbnail: Option<String>,
+            pub window: Option<String>,
+            pub windowparameter: Option<String>,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Favourite {
+            Window,
+            WindowParameter,
+            Thumbnail,
+            Path,
+        }
+    }
+
+    /// What a favourite opens: a media to play, a window, a script or an Android application
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Type {
+        #[default]
+        Media,
+        Window,
+        Script,
+        AndroidApp,
+        Unknown,
     }
 }
 
@@ -325,6 +831,7 @@ pub mod files {
 }
 
 pub mod global {
+    use enumset::EnumSetType;
     use std::time::Duration;
 
     #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -389,6 +896,478 @@ pub mod global {
             Toggle::Toggle
         }
     }
+
+    #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+    #[enumset(serialize_as_list)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Weekday {
+        Monday,
+        Tuesday,
+        Wednesday,
+        Thursday,
+        Friday,
+        Saturday,
+        Sunday,
+    }
+}
+
+pub mod gui {
+    pub mod property {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Name {
+            CurrentWindow,
+            CurrentControl,
+            Skin,
+            FullScreen,
+            StereoscopicMode,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct CurrentControl {
+            pub label: String,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct CurrentWindow {
+            pub id: usize,
+            pub label: String,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Skin {
+            pub id: String,
+            pub name: Option<String>,
+        }
+
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Value {
+            pub currentcontrol: Option<CurrentControl>,
+            pub currentwindow: Option<CurrentWindow>,
+            pub fullscreen: Option<bool>,
+            pub skin: Option<Skin>,
+            pub stereoscopicmode: Option<crate::types::gui::stereoscopy::Mode>,
+        }
+    }
+
+    pub mod stereoscopy {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum Kind {
+            Off,
+            SplitVertical,
+            SplitHorizontal,
+            RowInterleaved,
+            HardwareBased,
+            AnaglyphCyanRed,
+            AnaglyphGreenMagenta,
+            AnaglyphYellowBlue,
+            Monoscopic,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Mode {
+            pub label: String,
+            pub mode: Kind,
+        }
+
+        /// Stereoscopic mode to switch to with `GUI.SetStereoscopicMode`
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub enum SetMode {
+            Toggle,
+            #[serde(rename = "tomono")]
+            ToMono,
+            Next,
+            Previous,
+            Select,
+            Off,
+            SplitVertical,
+            SplitHorizontal,
+            RowInterleaved,
+            HardwareBased,
+            AnaglyphCyanRed,
+            AnaglyphGreenMagenta,
+            AnaglyphYellowBlue,
+            Monoscopic,
+        }
+    }
+
+    /// Image shown next to a notification
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum NotificationImage {
+        Info,
+        Warning,
+        Error,
+        /// Path or URL of any other image
+        Custom(String),
+    }
+
+    impl serde::Serialize for NotificationImage {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            serializer.serialize_str(match self {
+                Self::Info => "info",
+                Self::Warning => "warning",
+                Self::Error => "error",
+                Self::Custom(image) => image,
+            })
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Window {
+        AccessPoints,
+        Addon,
+        AddonBrowser,
+        AddonInformation,
+        AddonSettings,
+        AppearanceSettings,
+        BusyDialog,
+        BusyDialogNoCancel,
+        ContentSettings,
+        ContextMenu,
+        EventLog,
+        ExtendedProgressDialog,
+        Favourites,
+        FileBrowser,
+        FileManager,
+        FullScreenGame,
+        FullScreenInfo,
+        FullScreenLiveTv,
+        FullScreenLiveTvInput,
+        FullScreenLiveTvPreview,
+        FullScreenRadio,
+        FullScreenRadioInput,
+        FullScreenRadioPreview,
+        FullScreenVideo,
+        GameAdvancedSettings,
+        GameControllers,
+        GameOsd,
+        GamepadInput,
+        Games,
+        GameSettings,
+        GameStretchMode,
+        GameVideoFilter,
+        GameVideoRotation,
+        GameVolume,
+        Home,
+        InfoProviderSettings,
+        InterfaceSettings,
+        LibExportSettings,
+        LockSettings,
+        LoginScreen,
+        MediaFilter,
+        MediaSettings,
+        MediaSource,
+        MovieInformation,
+        Music,
+        MusicInformation,
+        MusicOsd,
+        MusicPlaylist,
+        MusicPlaylistEditor,
+        NetworkSetup,
+        Notification,
+        NumericInput,
+        OkDialog,
+        OsdAudioSettings,
+        OsdCmsSettings,
+        OsdSubtitleSettings,
+        OsdVideoSettings,
+        PeripheralSettings,
+        PictureInfo,
+        Pictures,
+        PlayerControls,
+        PlayerProcessInfo,
+        PlayerSettings,
+        Profiles,
+        ProfileSettings,
+        Programs,
+        ProgressDialog,
+        PvrChannelGuide,
+        PvrChannelManager,
+        PvrChannelScan,
+        PvrGroupManager,
+        PvrGuideInfo,
+        PvrGuideSearch,
+        PvrOsdChannels,
+        PvrOsdGuide,
+        PvrOsdTeletext,
+        PvrRadioRdsInfo,
+        PvrRecordingInfo,
+        PvrSettings,
+        PvrTimerSetting,
+        PvrUpdateProgress,
+        RadioChannels,
+        RadioGuide,
+        RadioRecordings,
+        RadioSearch,
+        RadioTimerRules,
+        RadioTimers,
+        ScreenCalibration,
+        Screensaver,
+        Seekbar,
+        SelectDialog,
+        ServiceSettings,
+        Settings,
+        ShutdownMenu,
+        SkinSettings,
+        SliderDialog,
+        Slideshow,
+        SmartPlaylistEditor,
+        SmartPlaylistRule,
+        SongInformation,
+        Splash,
+        Startup,
+        StartWindow,
+        Submenu,
+        SubtitleSearch,
+        SystemInfo,
+        SystemSettings,
+        Teletext,
+        TextViewer,
+        TvChannels,
+        TvGuide,
+        TvRecordings,
+        TvSearch,
+        TvTimerRules,
+        TvTimers,
+        VideoBookmarks,
+        VideoMenu,
+        VideoOsd,
+        VideoPlaylist,
+        Videos,
+        VideoTimeSeek,
+        VirtualKeyboard,
+        Visualisation,
+        VisualisationPresetList,
+        VolumeBar,
+        Weather,
+        YesNoDialog,
+    }
+}
+
+pub mod input {
+    /// Action to execute with `Input.ExecuteAction`, as listed in Kodi's keymaps
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Action {
+        AnalogFastForward,
+        AnalogMove,
+        AnalogMoveXLeft,
+        AnalogMoveXRight,
+        AnalogMoveYDown,
+        AnalogMoveYUp,
+        AnalogRewind,
+        AnalogSeekBack,
+        AnalogSeekForward,
+        AspectRatio,
+        AudioDelay,
+        AudioDelayMinus,
+        AudioDelayPlus,
+        AudioNextLanguage,
+        AudioToggleDigital,
+        Back,
+        Backspace,
+        BigStepBack,
+        BigStepForward,
+        Blue,
+        BrowseSubtitle,
+        ChannelDown,
+        ChannelNumberSeparator,
+        ChannelUp,
+        ChapterOrBigStepBack,
+        ChapterOrBigStepForward,
+        Close,
+        CodecInfo,
+        ContextMenu,
+        Copy,
+        CreateBookmark,
+        CreateEpisodeBookmark,
+        CursorLeft,
+        CursorRight,
+        CycleSubtitle,
+        DecreasePar,
+        DecreaseRating,
+        DecreaseVisRating,
+        Delete,
+        DoubleClick,
+        Down,
+        Enter,
+        Error,
+        FastForward,
+        Filter,
+        FilterClear,
+        FilterSms2,
+        FilterSms3,
+        FilterSms4,
+        FilterSms5,
+        FilterSms6,
+        FilterSms7,
+        FilterSms8,
+        FilterSms9,
+        FirstPage,
+        FullScreen,
+        Green,
+        GuiProfile,
+        Highlight,
+        IncreasePar,
+        IncreaseRating,
+        IncreaseVisRating,
+        Info,
+        JumpSms2,
+        JumpSms3,
+        JumpSms4,
+        JumpSms5,
+        JumpSms6,
+        JumpSms7,
+        JumpSms8,
+        JumpSms9,
+        LastPage,
+        Left,
+        LeftClick,
+        LockPreset,
+        LongClick,
+        LongPress,
+        Menu,
+        MiddleClick,
+        MouseDrag,
+        MouseMove,
+        Move,
+        MoveItemDown,
+        MoveItemUp,
+        Mute,
+        NextCalibration,
+        NextChannelGroup,
+        NextLetter,
+        NextPicture,
+        NextPreset,
+        NextResolution,
+        NextScene,
+        NextStereoMode,
+        NextSubtitle,
+        Noop,
+        Number0,
+        Number1,
+        Number2,
+        Number3,
+        Number4,
+        Number5,
+        Number6,
+        Number7,
+        Number8,
+        Number9,
+        Osd,
+        PageDown,
+        PageUp,
+        PanGesture,
+        ParentDir,
+        ParentFolder,
+        Pause,
+        Play,
+        PlayerDebug,
+        PlayerProcessInfo,
+        PlayerProgramSelect,
+        PlayerResolutionSelect,
+        Playlist,
+        PlayNext,
+        PlayPause,
+        PlayPvr,
+        PlayPvrRadio,
+        PlayPvrTv,
+        PreviousChannelGroup,
+        PreviousMenu,
+        PreviousPicture,
+        PreviousPreset,
+        PreviousScene,
+        PreviousStereoMode,
+        PrevLetter,
+        Queue,
+        RandomPreset,
+        Record,
+        Red,
+        ReloadKeymaps,
+        Rename,
+        ResetCalibration,
+        Rewind,
+        Right,
+        RightClick,
+        Rotate,
+        RotateCcw,
+        RotateGesture,
+        ScanItem,
+        Screenshot,
+        ScrollDown,
+        ScrollUp,
+        Select,
+        SetRating,
+        SettingsLevelChange,
+        SettingsReset,
+        Shift,
+        ShowPreset,
+        ShowSubtitles,
+        ShowTime,
+        ShowTimerRule,
+        ShowVideoMenu,
+        SkipNext,
+        SkipPrevious,
+        SmallStepBack,
+        StepBack,
+        StepForward,
+        StereoMode,
+        StereoModeToMono,
+        Stop,
+        SubtitleAlign,
+        SubtitleDelay,
+        SubtitleDelayMinus,
+        SubtitleDelayPlus,
+        SubtitleShiftDown,
+        SubtitleShiftUp,
+        SwipeDown,
+        SwipeLeft,
+        SwipeRight,
+        SwipeUp,
+        SwitchPlayer,
+        Symbols,
+        Tap,
+        ToggleCommSkip,
+        ToggleFont,
+        ToggleFullScreen,
+        ToggleStereoMode,
+        ToggleWatched,
+        Up,
+        VerticalShiftDown,
+        VerticalShiftUp,
+        VideoNextStream,
+        VoiceRecognizer,
+        VolAmpDown,
+        VolAmpUp,
+        VolumeAmplification,
+        VolumeDown,
+        VolumeUp,
+        WheelDown,
+        WheelUp,
+        Yellow,
+        ZoomGesture,
+        ZoomIn,
+        ZoomLevel1,
+        ZoomLevel2,
+        ZoomLevel3,
+        ZoomLevel4,
+        ZoomLevel5,
+        ZoomLevel6,
+        ZoomLevel7,
+        ZoomLevel8,
+        ZoomLevel9,
+        ZoomNormal,
+        ZoomOut,
+    }
 }
 
 pub mod library {
@@ -401,11 +1380,37 @@ pub mod library {
             pub paths: Vec<String>,
             pub sourceid: isize,
         }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Genre {
+            pub label: String,
+            pub genreid: usize,
+            #[serde(default)]
+            pub sourceid: Vec<isize>,
+            pub thumbnail: Option<String>,
+            pub title: Option<String>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Tag {
+            pub label: String,
+            pub tagid: usize,
+            pub title: Option<String>,
+        }
     }
 
     pub mod fields {
         use enumset::EnumSetType;
 
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Genre {
+            Title,
+            Thumbnail,
+            SourceId,
+        }
+
         #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
         #[enumset(serialize_as_list)]
         #[serde(rename_all = "lowercase")]
@@ -413,6 +1418,13 @@ pub mod library {
             File,
             Paths,
         }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Tag {
+            Title,
+        }
     }
 }
 
@@ -677,21 +1689,180 @@ pub mod list {
                 Playlist,
                 VirtualFolder,
             }
-        }
 
-        pub mod rule {
-            #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
             #[serde(rename_all = "lowercase")]
-            #[serde(untagged)]
-            pub enum Value {
-                One(String),
-                Many(Vec<String>),
+            pub enum Movies {
+                Title,
+                OriginalTitle,
+                Plot,
+                PlotOutline,
+                Tagline,
+                Votes,
+                Rating,
+                UserRating,
+                Time,
+                Writers,
+                PlayCount,
+                LastPlayed,
+                InProgress,
+                Genre,
+                Country,
+                Year,
+                Director,
+                Actor,
+                MpaaRating,
+                Top250,
+                Studio,
+                HasTrailer,
+                Filename,
+                Path,
+                Set,
+                Tag,
+                DateAdded,
+                VideoResolution,
+                AudioChannels,
+                AudioCount,
+                SubtitleCount,
+                VideoCodec,
+                AudioCodec,
+                AudioLanguage,
+                SubtitleLanguage,
+                VideoAspect,
+                Playlist,
+                VirtualFolder,
             }
 
-            impl From<String> for Value {
-                fn from(value: String) -> Self {
-                    Self::One(value)
-                }
+            #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum TVShows {
+                Title,
+                OriginalTitle,
+                Plot,
+                Status,
+                Votes,
+                Rating,
+                UserRating,
+                Year,
+                Genre,
+                Director,
+                Actor,
+                NumEpisodes,
+                NumWatched,
+                PlayCount,
+                Path,
+                Studio,
+                MpaaRating,
+                DateAdded,
+                LastPlayed,
+                InProgress,
+                Tag,
+                Playlist,
+                VirtualFolder,
+            }
+
+            #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum Episodes {
+                Title,
+                TvShow,
+                OriginalTitle,
+                Plot,
+                Votes,
+                Rating,
+                UserRating,
+                Time,
+                Writers,
+                AirDate,
+                PlayCount,
+                LastPlayed,
+                InProgress,
+                Genre,
+                Year,
+                Director,
+                Actor,
+                Episode,
+                Season,
+                Filename,
+                Path,
+                Studio,
+                MpaaRating,
+                DateAdded,
+                Tag,
+                VideoResolution,
+                AudioChannels,
+                AudioCount,
+                SubtitleCount,
+                VideoCodec,
+                AudioCodec,
+                AudioLanguage,
+                SubtitleLanguage,
+                VideoAspect,
+                Playlist,
+                VirtualFolder,
+            }
+
+            #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum MusicVideos {
+                Title,
+                Genre,
+                Album,
+                Year,
+                Artist,
+                Filename,
+                Path,
+                PlayCount,
+                LastPlayed,
+                Rating,
+                UserRating,
+                Time,
+                Director,
+                Studio,
+                Plot,
+                Tag,
+                DateAdded,
+                VideoResolution,
+                AudioChannels,
+                AudioCount,
+                SubtitleCount,
+                VideoCodec,
+                AudioCodec,
+                AudioLanguage,
+                SubtitleLanguage,
+                VideoAspect,
+                Playlist,
+                VirtualFolder,
+            }
+
+            #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum Textures {
+                TextureId,
+                Url,
+                CachedUrl,
+                LastHashCheck,
+                ImageHash,
+                Width,
+                Height,
+                UseCount,
+                LastUsed,
+            }
+        }
+
+        pub mod rule {
+            #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            #[serde(untagged)]
+            pub enum Value {
+                One(String),
+                Many(Vec<String>),
+            }
+
+            impl From<String> for Value {
+                fn from(value: String) -> Self {
+                    Self::One(value)
+                }
             }
 
             impl From<Vec<String>> for Value {
@@ -700,6 +1871,12 @@ pub mod list {
                 }
             }
 
+            impl From<&str> for Value {
+                fn from(value: &str) -> Self {
+                    Self::One(value.to_owned())
+                }
+            }
+
             #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
             pub struct Rule<T> {
                 pub operator: crate::types::list::filter::Operators,
@@ -712,6 +1889,16 @@ pub mod list {
             pub type Artists = Rule<crate::types::list::filter::fields::Artists>;
 
             pub type Songs = Rule<crate::types::list::filter::fields::Songs>;
+
+            pub type Movies = Rule<crate::types::list::filter::fields::Movies>;
+
+            pub type TVShows = Rule<crate::types::list::filter::fields::TVShows>;
+
+            pub type Episodes = Rule<crate::types::list::filter::fields::Episodes>;
+
+            pub type MusicVideos = Rule<crate::types::list::filter::fields::MusicVideos>;
+
+            pub type Textures = Rule<crate::types::list::filter::fields::Textures>;
         }
 
         #[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -791,6 +1978,16 @@ pub mod list {
         pub type Artists = Filter<rule::Artists>;
 
         pub type Songs = Filter<rule::Songs>;
+
+        pub type Movies = Filter<rule::Movies>;
+
+        pub type TVShows = Filter<rule::TVShows>;
+
+        pub type Episodes = Filter<rule::Episodes>;
+
+        pub type MusicVideos = Filter<rule::MusicVideos>;
+
+        pub type Textures = Filter<rule::Textures>;
     }
 
     pub mod item {
@@ -1135,18 +2332,119 @@ pub mod media {
         pub poster: Option<String>,
         pub thumb: Option<String>,
     }
+
+    /// Artwork to change, by type. Artwork set to `None` is removed.
+    pub type ArtworkSet = std::collections::HashMap<String, Option<String>>;
+
+    /// Decodes the original location of an artwork from the `image://` URL Kodi wraps it in,
+    /// such as the URLs of [`Artwork`].
+    ///
+    /// Returns `None` if `url` is not an `image://` URL.
+    pub fn decode_image_url(url: &str) -> Option<String> {
+        let encoded = url.strip_prefix("image://")?;
+        // The encoded location ends at the first slash, which may be followed by options
+        let encoded = match encoded.find('/') {
+            Some(end) => &encoded[..end],
+            None => encoded,
+        };
+        // Generated thumbnails are prefixed by their type, such as `video@`
+        let encoded = match encoded.find('@') {
+            Some(start) => &encoded[start + 1..],
+            None => encoded,
+        };
+        let decoded = percent_encoding::percent_decode_str(encoded).decode_utf8_lossy();
+        Some(decoded.into_owned())
+    }
+}
+
+pub mod notifications {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ItemType {
+        #[default]
+        Unknown,
+        Movie,
+        Episode,
+        MusicVideo,
+        Song,
+        Picture,
+        Channel,
+    }
+
+    /// Item a notification refers to; which fields are present depends on its type
+    #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct Item {
+        #[serde(rename = "type")]
+        pub kind: ItemType,
+        pub id: Option<isize>,
+        pub title: Option<String>,
+        pub year: Option<usize>,
+        pub episode: Option<usize>,
+        pub season: Option<usize>,
+        pub showtitle: Option<String>,
+        pub album: Option<String>,
+        pub artist: Option<String>,
+        pub track: Option<usize>,
+        pub file: Option<String>,
+        // pub channeltype: Option<PVR.Channel.Type>,
+    }
+
+    pub mod library {
+        pub mod audio {
+            #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+            #[serde(rename_all = "lowercase")]
+            pub enum Type {
+                Song,
+            }
+        }
+    }
 }
 
 pub mod player {
     pub mod audio {
         #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
         pub struct Stream {
-            bitrate: i64,
-            channels: u8,
-            codec: String,
-            index: usize,
-            language: String,
-            name: String,
+            pub bitrate: i64,
+            pub channels: u8,
+            pub codec: String,
+            pub index: usize,
+            pub language: String,
+            pub name: String,
+        }
+    }
+
+    pub mod notifications {
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Player {
+            #[serde(rename = "playerid")]
+            pub id: i8,
+            #[serde(default)]
+            pub speed: i64,
+        }
+
+        /// Same as `Global.Time` except every field is negative when seeking backward
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SeekOffset {
+            pub hours: i64,
+            pub minutes: i8,
+            pub seconds: i8,
+            pub milliseconds: i16,
+        }
+
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Seek {
+            #[serde(rename = "playerid")]
+            pub id: i8,
+            #[serde(default)]
+            pub speed: i64,
+            pub seekoffset: Option<SeekOffset>,
+            pub time: Option<crate::types::global::Time>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Data {
+            pub item: crate::types::notifications::Item,
+            pub player: Player,
         }
     }
 
@@ -1185,32 +2483,34 @@ pub mod player {
             VideoStreams,
         }
 
-        fn deserialize_opt_stream<'de, D>(
-            deserializer: D,
-        ) -> Result<Option<crate::types::player::audio::Stream>, D::Error>
+        /// Kodi returns an empty object rather than `null` when there is no current stream
+        fn deserialize_opt_stream<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
         where
             D: serde::de::Deserializer<'de>,
+            T: serde::Deserialize<'de>,
         {
             use serde::Deserialize;
 
             #[derive(serde::Deserialize)]
+            #[serde(deny_unknown_fields)]
             struct EmptyStream {}
 
+            // Streams whose fields all have defaults would also match an empty object
             #[derive(serde::Deserialize)]
             #[serde(untagged)]
-            enum OptionStream {
-                Stream(crate::types::player::audio::Stream),
+            enum OptionStream<T> {
                 Empty(EmptyStream),
+                Stream(T),
             }
 
-            let opt_stream = Option::<OptionStream>::deserialize(deserializer)?;
+            let opt_stream = Option::<OptionStream<T>>::deserialize(deserializer)?;
             Ok(match opt_stream {
                 Some(OptionStream::Empty(_)) | None => None,
                 Some(OptionStream::Stream(stream)) => Some(stream),
             })
         }
 
-        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
         pub struct Value {
             pub audiostreams: Option<Vec<crate::types::player::audio::Stream>>,
             pub canchangespeed: Option<bool>,
@@ -1225,8 +2525,16 @@ pub mod player {
                 deserialize_with = "crate::types::player::property::deserialize_opt_stream"
             )]
             pub currentaudiostream: Option<crate::types::player::audio::Stream>,
-            pub currensubtitle: Option<crate::types::player::Subtitle>,
-            pub currentvideostream: Option<crate::types::player::video::Stream>,
+            #[serde(
+                default,
+                deserialize_with = "crate::types::player::property::deserialize_opt_stream"
+            )]
+            pub currentsubtitle: Option<crate::types::video::streams::Subtitle>,
+            #[serde(
+                default,
+                deserialize_with = "crate::types::player::property::deserialize_opt_stream"
+            )]
+            pub currentvideostream: Option<crate::types::video::streams::Video>,
             pub live: Option<bool>,
             pub partymode: Option<bool>,
             // percentage: Option<f32>,
@@ -1239,24 +2547,12 @@ pub mod player {
             pub shuffled: Option<bool>,
             pub speed: Option<i64>,
             pub subtitleenabled: Option<bool>,
-            pub subtitles: Option<Vec<crate::types::player::Subtitle>>,
+            pub subtitles: Option<Vec<crate::types::video::streams::Subtitle>>,
             pub time: Option<crate::types::global::Time>,
             pub totaltime: Option<crate::types::global::Time>,
             #[serde(rename = "type")]
             pub kind: Option<crate::types::player::Type>,
-            pub videostreams: Option<Vec<crate::types::player::video::Stream>>,
-        }
-    }
-
-    pub mod video {
-        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
-        pub struct Stream {
-            codec: String,
-            height: usize,
-            index: usize,
-            language: String,
-            name: String,
-            width: usize,
+            pub videostreams: Option<Vec<crate::types::video::streams::Video>>,
         }
     }
 
@@ -1311,13 +2607,6 @@ pub mod player {
         speed: i8,
     }
 
-    #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
-    pub struct Subtitle {
-        index: usize,
-        language: String,
-        name: String,
-    }
-
     #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
     #[serde(rename_all = "lowercase")]
     pub enum Type {
@@ -1331,6 +2620,23 @@ pub mod player {
             Self::Video
         }
     }
+
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ViewMode {
+        #[default]
+        Normal,
+        Zoom,
+        Stretch4x3,
+        WideZoom,
+        Stretch16x9,
+        Original,
+        Stretch16x9Nonlin,
+        Zoom120Width,
+        Zoom110Width,
+        /// Set with custom zoom, pixel ratio, vertical shift or non linear stretch values
+        Custom,
+    }
 }
 
 pub mod playlist {
@@ -1401,41 +2707,1323 @@ pub mod playlist {
     }
 }
 
-pub mod video {
-    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
-    pub struct CastMember {
-        pub name: String,
-        pub order: String,
-        pub role: String,
-        pub thumbnail: Option<String>,
+pub mod profiles {
+    pub mod details {
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Profile {
+            pub label: String,
+            pub lockmode: Option<usize>,
+            pub thumbnail: Option<String>,
+        }
     }
 
-    pub type Cast = Vec<CastMember>;
+    pub mod fields {
+        use enumset::EnumSetType;
 
-    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
-    pub struct Resume {
-        pub position: f64,
-        pub total: f64,
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Profile {
+            Thumbnail,
+            LockMode,
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Encryption {
+        None,
+        Md5,
+    }
+
+    #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct Password {
+        pub value: String,
+        pub encryption: Encryption,
     }
 }
 
-#[test]
-fn test_global_time() {
-    use crate::types::global::Time;
-    use std::time::Duration;
+pub mod pvr {
+    pub mod details {
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Broadcast {
+            pub label: String,
+            pub broadcastid: usize,
+            pub cast: Option<String>,
+            pub director: Option<String>,
+            pub endtime: Option<String>,
+            pub episodename: Option<String>,
+            pub episodenum: Option<usize>,
+            pub episodepart: Option<usize>,
+            pub firstaired: Option<String>,
+            pub genre: Option<String>,
+            pub hasrecording: Option<bool>,
+            pub hastimer: Option<bool>,
+            pub hastimerrule: Option<bool>,
+            pub imdbnumber: Option<String>,
+            pub isactive: Option<bool>,
+            pub isseries: Option<bool>,
+            pub originaltitle: Option<String>,
+            pub parentalrating: Option<usize>,
+            pub plot: Option<String>,
+            pub plotoutline: Option<String>,
+            pub progress: Option<usize>,
+            pub progresspercentage: Option<f64>,
+            pub rating: Option<usize>,
+            pub recording: Option<String>,
+            pub runtime: Option<usize>,
+            pub starttime: Option<String>,
+            pub thumbnail: Option<String>,
+            pub title: Option<String>,
+            pub wasactive: Option<bool>,
+            pub writer: Option<String>,
+            pub year: Option<usize>,
+        }
 
-    let dur = Duration::new(132, 0);
-    let time: Time = dur.into();
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Channel {
+            pub label: String,
+            pub broadcastnext: Option<Broadcast>,
+            pub broadcastnow: Option<Broadcast>,
+            pub channel: Option<String>,
+            pub channelid: usize,
+            pub channelnumber: Option<usize>,
+            pub channeltype: Option<crate::types::pvr::ChannelType>,
+            pub hidden: Option<bool>,
+            pub icon: Option<String>,
+            pub isrecording: Option<bool>,
+            pub lastplayed: Option<String>,
+            pub locked: Option<bool>,
+            pub subchannelnumber: Option<usize>,
+            pub thumbnail: Option<String>,
+            pub uniqueid: usize,
+        }
 
-    assert_eq!(
-        time,
-        Time {
-            hours: 0,
-            minutes: 2,
-            seconds: 12,
-            milliseconds: 0,
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct ChannelGroup {
+            pub label: String,
+            pub channelgroupid: usize,
+            pub channeltype: crate::types::pvr::ChannelType,
         }
-    );
 
-    assert_eq!(Duration::from(time), dur);
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct ChannelGroupExtended {
+            pub label: String,
+            pub channelgroupid: usize,
+            pub channeltype: crate::types::pvr::ChannelType,
+            #[serde(default)]
+            pub channels: Vec<Channel>,
+            pub limits: crate::types::list::LimitsReturned,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Recording {
+            pub label: String,
+            pub art: Option<crate::types::media::Artwork>,
+            pub channel: Option<String>,
+            pub channeluid: Option<usize>,
+            pub directory: Option<String>,
+            pub endtime: Option<String>,
+            pub epgeventid: Option<usize>,
+            pub file: Option<String>,
+            pub genre: Option<String>,
+            pub icon: Option<String>,
+            pub isdeleted: Option<bool>,
+            pub lifetime: Option<usize>,
+            pub playcount: Option<usize>,
+            pub plot: Option<String>,
+            pub plotoutline: Option<String>,
+            pub radio: Option<bool>,
+            pub recordingid: usize,
+            pub resume: Option<crate::types::video::Resume>,
+            pub runtime: Option<usize>,
+            pub starttime: Option<String>,
+            pub streamurl: Option<String>,
+            pub title: Option<String>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Timer {
+            pub label: String,
+            pub channelid: Option<isize>,
+            pub directory: Option<String>,
+            pub endanytime: Option<bool>,
+            pub endmargin: Option<usize>,
+            pub endtime: Option<String>,
+            pub epgsearchstring: Option<String>,
+            pub epguid: Option<usize>,
+            pub file: Option<String>,
+            pub firstday: Option<String>,
+            pub fulltextepgsearch: Option<bool>,
+            pub ismanual: Option<bool>,
+            pub isradio: Option<bool>,
+            pub isreadonly: Option<bool>,
+            pub istimerrule: Option<bool>,
+            pub lifetime: Option<usize>,
+            pub maxrecordings: Option<usize>,
+            pub preventduplicateepisodes: Option<usize>,
+            pub priority: Option<isize>,
+            pub recordinggroup: Option<usize>,
+            pub runtime: Option<usize>,
+            pub startanytime: Option<bool>,
+            pub startmargin: Option<usize>,
+            pub starttime: Option<String>,
+            pub state: Option<crate::types::pvr::TimerState>,
+            pub summary: Option<String>,
+            pub timerid: usize,
+            pub title: Option<String>,
+            #[serde(default)]
+            pub weekdays: enumset::EnumSet<crate::types::global::Weekday>,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Broadcast {
+            Title,
+            Plot,
+            PlotOutline,
+            StartTime,
+            EndTime,
+            Runtime,
+            Progress,
+            ProgressPercentage,
+            Genre,
+            EpisodeName,
+            EpisodeNum,
+            EpisodePart,
+            FirstAired,
+            HasTimer,
+            IsActive,
+            ParentalRating,
+            WasActive,
+            Thumbnail,
+            Rating,
+            OriginalTitle,
+            Cast,
+            Director,
+            Writer,
+            Year,
+            ImdbNumber,
+            HasTimerRule,
+            HasRecording,
+            Recording,
+            IsSeries,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Channel {
+            Thumbnail,
+            ChannelType,
+            Hidden,
+            Locked,
+            Channel,
+            LastPlayed,
+            BroadcastNow,
+            BroadcastNext,
+            UniqueId,
+            Icon,
+            ChannelNumber,
+            SubChannelNumber,
+            IsRecording,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Recording {
+            Title,
+            Plot,
+            PlotOutline,
+            Genre,
+            PlayCount,
+            Resume,
+            Channel,
+            StartTime,
+            EndTime,
+            Runtime,
+            Lifetime,
+            Icon,
+            Art,
+            StreamUrl,
+            File,
+            Directory,
+            Radio,
+            IsDeleted,
+            EpgEventId,
+            ChannelUid,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Timer {
+            Title,
+            Summary,
+            ChannelId,
+            IsRadio,
+            IsTimerRule,
+            IsManual,
+            StartTime,
+            EndTime,
+            Runtime,
+            Lifetime,
+            FirstDay,
+            Weekdays,
+            Priority,
+            StartMargin,
+            EndMargin,
+            State,
+            File,
+            Directory,
+            PreventDuplicateEpisodes,
+            StartAnyTime,
+            EndAnyTime,
+            EpgSearchString,
+            FullTextEpgSearch,
+            RecordingGroup,
+            MaxRecordings,
+            EpgUid,
+            IsReadOnly,
+        }
+    }
+
+    pub mod property {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Name {
+            Available,
+            Recording,
+            Scanning,
+        }
+
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Value {
+            pub available: Option<bool>,
+            pub recording: Option<bool>,
+            pub scanning: Option<bool>,
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum AllChannels {
+        AllTv,
+        AllRadio,
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(untagged)]
+    pub enum ChannelGroupId {
+        Id(usize),
+        /// Group of all the TV or radio channels
+        All(AllChannels),
+    }
+
+    impl From<usize> for ChannelGroupId {
+        fn from(id: usize) -> Self {
+            Self::Id(id)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum ChannelType {
+        #[default]
+        Tv,
+        Radio,
+    }
+
+    fn serialize_current<S>(serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str("current")
+    }
+
+    /// Channel to record with `PVR.Record`
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize)]
+    #[serde(untagged)]
+    pub enum RecordChannel {
+        /// Channel currently playing
+        #[default]
+        #[serde(serialize_with = "crate::types::pvr::serialize_current")]
+        Current,
+        Id(usize),
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum TimerState {
+        Unknown,
+        New,
+        Scheduled,
+        Recording,
+        Completed,
+        Aborted,
+        Cancelled,
+        #[serde(rename = "conflict_ok")]
+        ConflictOk,
+        #[serde(rename = "conflict_notok")]
+        ConflictNotOk,
+        Error,
+        Disabled,
+    }
+}
+
+pub mod setting {
+    pub mod details {
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Section {
+            pub id: String,
+            pub label: String,
+            pub help: Option<String>,
+            #[serde(default)]
+            pub categories: Vec<Category>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Category {
+            pub id: String,
+            pub label: String,
+            pub help: Option<String>,
+            #[serde(default)]
+            pub groups: Vec<Group>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Group {
+            pub id: String,
+            #[serde(default)]
+            pub settings: Vec<Setting>,
+        }
+
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(rename_all = "lowercase")]
+        pub enum ControlType {
+            Toggle,
+            Spinner,
+            Edit,
+            Button,
+            List,
+            Slider,
+            Range,
+            Label,
+        }
+
+        /// GUI control used to change a setting, only some fields apply to each type of control
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Control {
+            #[serde(rename = "type")]
+            pub kind: ControlType,
+            pub delayed: bool,
+            pub format: String,
+            pub heading: Option<String>,
+            pub hidden: Option<bool>,
+            pub verifynewvalue: Option<bool>,
+            pub multiselect: Option<bool>,
+            pub popup: Option<bool>,
+            pub formatlabel: Option<String>,
+            pub formatvalue: Option<String>,
+            pub minimumlabel: Option<String>,
+        }
+
+        /// Value a setting can be set to, along with its label
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingOption<T> {
+            pub label: String,
+            pub value: T,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingBool {
+            pub default: bool,
+            pub value: bool,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingInt {
+            pub default: i64,
+            pub value: i64,
+            pub minimum: Option<i64>,
+            pub maximum: Option<i64>,
+            pub step: Option<i64>,
+            /// When not empty, the only values the setting can be set to
+            #[serde(default)]
+            pub options: Vec<SettingOption<i64>>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingNumber {
+            pub default: f64,
+            pub value: f64,
+            pub minimum: f64,
+            pub maximum: f64,
+            pub step: f64,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingString {
+            pub default: String,
+            pub value: String,
+            pub allowempty: bool,
+            /// When not empty, the only values the setting can be set to
+            #[serde(default)]
+            pub options: Vec<SettingOption<String>>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingAction {
+            pub data: String,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingList {
+            pub default: Vec<crate::types::setting::Value>,
+            pub value: Vec<crate::types::setting::Value>,
+            /// Definition of each element of the list
+            pub definition: Box<Setting>,
+            pub delimiter: String,
+            pub elementtype: crate::types::setting::Type,
+            pub minimumitems: Option<usize>,
+            pub maximumitems: Option<usize>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingPath {
+            #[serde(flatten)]
+            pub string: SettingString,
+            pub writable: bool,
+            #[serde(default)]
+            pub sources: Vec<String>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct SettingAddon {
+            #[serde(flatten)]
+            pub string: SettingString,
+            pub addontype: String,
+        }
+
+        /// Current value, default value and constraints of a setting, depending on its type
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(tag = "type", rename_all = "lowercase")]
+        pub enum Definition {
+            Boolean(SettingBool),
+            Integer(SettingInt),
+            Number(SettingNumber),
+            String(SettingString),
+            Action(SettingAction),
+            List(SettingList),
+            Path(SettingPath),
+            Addon(SettingAddon),
+            Date(SettingString),
+            Time(SettingString),
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Setting {
+            pub id: String,
+            pub label: String,
+            pub help: Option<String>,
+            pub enabled: bool,
+            pub level: crate::types::setting::Level,
+            pub parent: Option<String>,
+            pub control: Option<Control>,
+            #[serde(flatten)]
+            pub definition: Definition,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Category {
+            Settings,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Section {
+            Categories,
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Level {
+        Basic,
+        Standard,
+        Advanced,
+        Expert,
+    }
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Type {
+        Boolean,
+        Integer,
+        Number,
+        String,
+        Action,
+        List,
+        Path,
+        Addon,
+        Date,
+        Time,
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(untagged)]
+    pub enum Value {
+        Boolean(bool),
+        Integer(i64),
+        Number(f64),
+        String(String),
+        List(Vec<Value>),
+    }
+
+    impl From<bool> for Value {
+        fn from(value: bool) -> Self {
+            Self::Boolean(value)
+        }
+    }
+
+    impl From<i64> for Value {
+        fn from(value: i64) -> Self {
+            Self::Integer(value)
+        }
+    }
+
+    impl From<f64> for Value {
+        fn from(value: f64) -> Self {
+            Self::Number(value)
+        }
+    }
+
+    impl From<String> for Value {
+        fn from(value: String) -> Self {
+            Self::String(value)
+        }
+    }
+
+    impl From<&str> for Value {
+        fn from(value: &str) -> Self {
+            Self::String(value.to_owned())
+        }
+    }
+
+    impl From<Vec<Value>> for Value {
+        fn from(value: Vec<Value>) -> Self {
+            Self::List(value)
+        }
+    }
+}
+
+pub mod system {
+    pub mod property {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Name {
+            CanShutdown,
+            CanSuspend,
+            CanHibernate,
+            CanReboot,
+        }
+
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Value {
+            pub canhibernate: Option<bool>,
+            pub canreboot: Option<bool>,
+            pub canshutdown: Option<bool>,
+            pub cansuspend: Option<bool>,
+        }
+    }
+}
+
+pub mod textures {
+    pub mod details {
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Size {
+            /// Size of the texture, 1 being the largest
+            pub size: Option<usize>,
+            pub width: Option<usize>,
+            pub height: Option<usize>,
+            pub usecount: Option<usize>,
+            pub lastused: Option<String>,
+        }
+
+        #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Texture {
+            pub textureid: usize,
+            /// Original source URL
+            pub url: Option<String>,
+            /// Cached URL on disk
+            pub cachedurl: Option<String>,
+            pub lasthashcheck: Option<String>,
+            pub imagehash: Option<String>,
+            #[serde(default)]
+            pub sizes: Vec<Size>,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Texture {
+            Url,
+            CachedUrl,
+            LastHashCheck,
+            ImageHash,
+            Sizes,
+        }
+    }
+}
+
+pub mod video {
+    pub mod details {
+        use std::collections::HashMap;
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Movie {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub dateadded: Option<String>,
+            pub file: Option<String>,
+            pub lastplayed: Option<String>,
+            pub plot: Option<String>,
+            #[serde(default)]
+            pub director: Vec<String>,
+            pub resume: Option<crate::types::video::Resume>,
+            pub runtime: Option<usize>,
+            pub streamdetails: Option<crate::types::video::Streams>,
+            #[serde(default)]
+            pub cast: crate::types::video::Cast,
+            #[serde(default)]
+            pub country: Vec<String>,
+            #[serde(default)]
+            pub genre: Vec<String>,
+            pub imdbnumber: Option<String>,
+            pub movieid: usize,
+            pub mpaa: Option<String>,
+            pub originaltitle: Option<String>,
+            pub plotoutline: Option<String>,
+            pub premiered: Option<String>,
+            pub rating: Option<f64>,
+            pub ratings: Option<crate::types::video::Ratings>,
+            pub set: Option<String>,
+            pub setid: Option<isize>,
+            #[serde(default)]
+            pub showlink: Vec<String>,
+            pub sorttitle: Option<String>,
+            #[serde(default)]
+            pub studio: Vec<String>,
+            #[serde(default)]
+            pub tag: Vec<String>,
+            pub tagline: Option<String>,
+            pub top250: Option<usize>,
+            pub trailer: Option<String>,
+            pub uniqueid: Option<HashMap<String, String>>,
+            pub userrating: Option<usize>,
+            pub votes: Option<String>,
+            #[serde(default)]
+            pub writer: Vec<String>,
+            pub year: Option<usize>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct MovieSet {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub plot: Option<String>,
+            pub setid: usize,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct MovieSetExtended {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub plot: Option<String>,
+            pub setid: usize,
+            pub limits: crate::types::list::LimitsReturned,
+            #[serde(default)]
+            pub movies: Vec<Movie>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct TVShow {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub dateadded: Option<String>,
+            pub file: Option<String>,
+            pub lastplayed: Option<String>,
+            pub plot: Option<String>,
+            #[serde(default)]
+            pub cast: crate::types::video::Cast,
+            pub episode: Option<usize>,
+            pub episodeguide: Option<String>,
+            #[serde(default)]
+            pub genre: Vec<String>,
+            pub imdbnumber: Option<String>,
+            pub mpaa: Option<String>,
+            pub originaltitle: Option<String>,
+            pub premiered: Option<String>,
+            pub rating: Option<f64>,
+            pub ratings: Option<crate::types::video::Ratings>,
+            pub runtime: Option<usize>,
+            pub season: Option<usize>,
+            pub sorttitle: Option<String>,
+            #[serde(default)]
+            pub studio: Vec<String>,
+            #[serde(default)]
+            pub tag: Vec<String>,
+            pub tvshowid: usize,
+            pub uniqueid: Option<HashMap<String, String>>,
+            pub userrating: Option<usize>,
+            pub votes: Option<String>,
+            pub watchedepisodes: Option<usize>,
+            pub year: Option<usize>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Season {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub episode: Option<usize>,
+            pub season: isize,
+            pub seasonid: usize,
+            pub showtitle: Option<String>,
+            pub title: Option<String>,
+            pub tvshowid: Option<isize>,
+            pub userrating: Option<usize>,
+            pub watchedepisodes: Option<usize>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct Episode {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub dateadded: Option<String>,
+            pub file: Option<String>,
+            pub lastplayed: Option<String>,
+            pub plot: Option<String>,
+            #[serde(default)]
+            pub director: Vec<String>,
+            pub resume: Option<crate::types::video::Resume>,
+            pub runtime: Option<usize>,
+            pub streamdetails: Option<crate::types::video::Streams>,
+            #[serde(default)]
+            pub cast: crate::types::video::Cast,
+            pub episode: Option<isize>,
+            pub episodeid: usize,
+            pub firstaired: Option<String>,
+            pub originaltitle: Option<String>,
+            pub productioncode: Option<String>,
+            pub rating: Option<f64>,
+            pub ratings: Option<crate::types::video::Ratings>,
+            pub season: Option<isize>,
+            pub seasonid: Option<isize>,
+            pub showtitle: Option<String>,
+            pub specialsortepisode: Option<isize>,
+            pub specialsortseason: Option<isize>,
+            pub tvshowid: Option<isize>,
+            pub uniqueid: Option<HashMap<String, String>>,
+            pub userrating: Option<usize>,
+            pub votes: Option<String>,
+            #[serde(default)]
+            pub writer: Vec<String>,
+        }
+
+        #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        pub struct MusicVideo {
+            pub label: String,
+            pub fanart: Option<String>,
+            pub thumbnail: Option<String>,
+            pub art: Option<crate::types::media::Artwork>,
+            pub playcount: Option<usize>,
+            pub title: Option<String>,
+            pub dateadded: Option<String>,
+            pub file: Option<String>,
+            pub lastplayed: Option<String>,
+            pub plot: Option<String>,
+            #[serde(default)]
+            pub director: Vec<String>,
+            pub resume: Option<crate::types::video::Resume>,
+            pub runtime: Option<usize>,
+            pub streamdetails: Option<crate::types::video::Streams>,
+            pub album: Option<String>,
+            #[serde(default)]
+            pub artist: Vec<String>,
+            #[serde(default)]
+            pub genre: Vec<String>,
+            pub musicvideoid: usize,
+            pub premiered: Option<String>,
+            pub rating: Option<f64>,
+            #[serde(default)]
+            pub studio: Vec<String>,
+            #[serde(default)]
+            pub tag: Vec<String>,
+            pub track: Option<isize>,
+            pub userrating: Option<usize>,
+            pub year: Option<usize>,
+        }
+    }
+
+    pub mod fields {
+        use enumset::EnumSetType;
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Movie {
+            Title,
+            Genre,
+            Year,
+            Rating,
+            Director,
+            Trailer,
+            Tagline,
+            Plot,
+            PlotOutline,
+            OriginalTitle,
+            LastPlayed,
+            PlayCount,
+            Writer,
+            Studio,
+            Mpaa,
+            Cast,
+            Country,
+            ImdbNumber,
+            Runtime,
+            Set,
+            ShowLink,
+            StreamDetails,
+            Top250,
+            Votes,
+            FanArt,
+            Thumbnail,
+            File,
+            SortTitle,
+            Resume,
+            SetId,
+            DateAdded,
+            Tag,
+            Art,
+            UserRating,
+            Ratings,
+            Premiered,
+            UniqueId,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum MovieSet {
+            Title,
+            PlayCount,
+            FanArt,
+            Thumbnail,
+            Art,
+            Plot,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum TVShow {
+            Title,
+            Genre,
+            Year,
+            Rating,
+            Plot,
+            Studio,
+            Mpaa,
+            Cast,
+            PlayCount,
+            Episode,
+            ImdbNumber,
+            Premiered,
+            Votes,
+            LastPlayed,
+            FanArt,
+            Thumbnail,
+            File,
+            OriginalTitle,
+            SortTitle,
+            EpisodeGuide,
+            Season,
+            WatchedEpisodes,
+            DateAdded,
+            Tag,
+            Art,
+            UserRating,
+            Ratings,
+            Runtime,
+            UniqueId,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Season {
+            Season,
+            ShowTitle,
+            PlayCount,
+            Episode,
+            FanArt,
+            Thumbnail,
+            TvShowId,
+            WatchedEpisodes,
+            Art,
+            UserRating,
+            Title,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum Episode {
+            Title,
+            Plot,
+            Votes,
+            Rating,
+            Writer,
+            FirstAired,
+            PlayCount,
+            Runtime,
+            Director,
+            ProductionCode,
+            Season,
+            Episode,
+            OriginalTitle,
+            ShowTitle,
+            Cast,
+            StreamDetails,
+            LastPlayed,
+            FanArt,
+            Thumbnail,
+            File,
+            Resume,
+            TvShowId,
+            DateAdded,
+            UniqueId,
+            Art,
+            SpecialSortSeason,
+            SpecialSortEpisode,
+            UserRating,
+            SeasonId,
+            Ratings,
+        }
+
+        #[derive(Debug, EnumSetType, serde::Deserialize, serde::Serialize)]
+        #[enumset(serialize_as_list)]
+        #[serde(rename_all = "lowercase")]
+        pub enum MusicVideo {
+            Title,
+            PlayCount,
+            Runtime,
+            Director,
+            Studio,
+            Year,
+            Plot,
+            Album,
+            Artist,
+            Genre,
+            Track,
+            StreamDetails,
+            LastPlayed,
+            FanArt,
+            Thumbnail,
+            File,
+            Resume,
+            DateAdded,
+            Tag,
+            Art,
+            Rating,
+            UserRating,
+            Premiered,
+        }
+    }
+
+    pub mod streams {
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(default)]
+        pub struct Audio {
+            pub channels: usize,
+            pub codec: String,
+            pub language: String,
+        }
+
+        /// Also used for the subtitles of the player, which come with their `index` and `name`
+        #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(default)]
+        pub struct Subtitle {
+            pub index: usize,
+            pub language: String,
+            pub name: String,
+        }
+
+        /// Also used for the video streams of the player, which come with their `index`,
+        /// `language` and `name` but without `aspect` and `duration`
+        #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+        #[serde(default)]
+        pub struct Video {
+            pub aspect: f64,
+            pub codec: String,
+            pub duration: usize,
+            pub height: usize,
+            pub index: usize,
+            pub language: String,
+            pub name: String,
+            pub width: usize,
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct CastMember {
+        pub name: String,
+        pub order: usize,
+        pub role: String,
+        pub thumbnail: Option<String>,
+    }
+
+    pub type Cast = Vec<CastMember>;
+
+    /// Library a video genre or tag belongs to
+    #[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Content {
+        Movie,
+        TvShow,
+        MusicVideo,
+    }
+
+    /// Part of the video library to clean
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(rename_all = "lowercase")]
+    pub enum CleanContent {
+        #[default]
+        Video,
+        Movies,
+        TvShows,
+        MusicVideos,
+    }
+
+    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct Rating {
+        #[serde(default)]
+        pub default: bool,
+        pub rating: f64,
+        pub votes: Option<usize>,
+    }
+
+    /// Ratings by source, such as `imdb` or `themoviedb`
+    pub type Ratings = std::collections::HashMap<String, Rating>;
+
+    #[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct Resume {
+        pub position: f64,
+        pub total: f64,
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+    pub struct Streams {
+        #[serde(default)]
+        pub audio: Vec<streams::Audio>,
+        #[serde(default)]
+        pub subtitle: Vec<streams::Subtitle>,
+        #[serde(default)]
+        pub video: Vec<streams::Video>,
+    }
+}
+
+pub mod xbmc {
+    macro_rules! define_info {
+        ($( #[$attr:meta] )* $name:ident { $( $variant:ident => $info:literal ),* $(,)? }) => {
+            $( #[$attr] )*
+            #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+            pub enum $name {
+                $( #[doc = $info] $variant, )*
+                /// Any other one, see <https://kodi.wiki/view/InfoLabels>
+                Other(String),
+            }
+
+            impl $name {
+                pub fn as_str(&self) -> &str {
+                    match self {
+                        $( Self::$variant => $info, )*
+                        Self::Other(info) => info,
+                    }
+                }
+            }
+
+            impl From<&str> for $name {
+                fn from(info: &str) -> Self {
+                    match info {
+                        $( $info => Self::$variant, )*
+                        _ => Self::Other(info.to_owned()),
+                    }
+                }
+            }
+
+            impl serde::Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(self.as_str())
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let info = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    Ok(Self::from(info.as_ref()))
+                }
+            }
+        };
+    }
+
+    define_info!(
+        /// Info label retrieved with `XBMC.GetInfoLabels`
+        InfoLabel {
+            MusicPlayerAlbum => "MusicPlayer.Album",
+            MusicPlayerArtist => "MusicPlayer.Artist",
+            MusicPlayerBitRate => "MusicPlayer.BitRate",
+            MusicPlayerChannels => "MusicPlayer.Channels",
+            MusicPlayerCodec => "MusicPlayer.Codec",
+            MusicPlayerSampleRate => "MusicPlayer.SampleRate",
+            MusicPlayerTitle => "MusicPlayer.Title",
+            NetworkIpAddress => "Network.IPAddress",
+            PlayerDuration => "Player.Duration",
+            PlayerFilenameAndPath => "Player.FilenameAndPath",
+            PlayerTime => "Player.Time",
+            PlayerTimeRemaining => "Player.TimeRemaining",
+            PlayerVolume => "Player.Volume",
+            SystemBuildDate => "System.BuildDate",
+            SystemBuildVersion => "System.BuildVersion",
+            SystemCpuUsage => "System.CpuUsage",
+            SystemCurrentWindow => "System.CurrentWindow",
+            SystemDate => "System.Date",
+            SystemFreeSpace => "System.FreeSpace",
+            SystemFriendlyName => "System.FriendlyName",
+            SystemMemoryUsedPercent => "System.Memory(used.percent)",
+            SystemScreenResolution => "System.ScreenResolution",
+            SystemTime => "System.Time",
+            SystemUptime => "System.Uptime",
+            VideoPlayerAudioChannels => "VideoPlayer.AudioChannels",
+            VideoPlayerAudioCodec => "VideoPlayer.AudioCodec",
+            VideoPlayerTitle => "VideoPlayer.Title",
+            VideoPlayerVideoCodec => "VideoPlayer.VideoCodec",
+            VideoPlayerVideoResolution => "VideoPlayer.VideoResolution",
+        }
+    );
+
+    define_info!(
+        /// Info boolean retrieved with `XBMC.GetInfoBooleans`
+        InfoBoolean {
+            LibraryHasContentMovies => "Library.HasContent(Movies)",
+            LibraryHasContentMusic => "Library.HasContent(Music)",
+            LibraryHasContentTvShows => "Library.HasContent(TVShows)",
+            LibraryIsScanning => "Library.IsScanning",
+            LibraryIsScanningMusic => "Library.IsScanningMusic",
+            LibraryIsScanningVideo => "Library.IsScanningVideo",
+            PlayerHasAudio => "Player.HasAudio",
+            PlayerHasMedia => "Player.HasMedia",
+            PlayerHasVideo => "Player.HasVideo",
+            PlayerMuted => "Player.Muted",
+            PlayerPaused => "Player.Paused",
+            PlayerPlaying => "Player.Playing",
+            PvrIsRecording => "PVR.IsRecording",
+            PvrHasTimer => "PVR.HasTimer",
+            SystemHasNetwork => "System.HasNetwork",
+            SystemIsFullscreen => "System.IsFullscreen",
+            SystemPlatformAndroid => "System.Platform.Android",
+            SystemPlatformLinux => "System.Platform.Linux",
+            SystemPlatformOsx => "System.Platform.OSX",
+            SystemPlatformWindows => "System.Platform.Windows",
+            SystemScreenSaverActive => "System.ScreenSaverActive",
+        }
+    );
+}
+
+#[test]
+fn test_global_time() {
+    use crate::types::global::Time;
+    use std::time::Duration;
+
+    let dur = Duration::new(132, 0);
+    let time: Time = dur.into();
+
+    assert_eq!(
+        time,
+        Time {
+            hours: 0,
+            minutes: 2,
+            seconds: 12,
+            milliseconds: 0,
+        }
+    );
+
+    assert_eq!(Duration::from(time), dur);
+}
+
+#[test]
+fn test_media_decode_image_url() {
+    use crate::types::media::decode_image_url;
+
+    assert_eq!(
+        decode_image_url("image://http%3a%2f%2fexample.com%2fcover.jpg/").as_deref(),
+        Some("http://example.com/cover.jpg")
+    );
+    assert_eq!(
+        decode_image_url("image://music@%2fmusic%2fsong.flac/").as_deref(),
+        Some("/music/song.flac")
+    );
+    assert_eq!(decode_image_url("/music/cover.jpg"), None);
 }
@@ -0,0 +1,141 @@
+use crate::multiplex::{Connection, Multiplexer};
+use crate::{KodiNotificationTransport, KodiTransport, KodiTransportError};
+use async_trait::async_trait;
+use futures::{SinkExt, StreamExt};
+use std::sync::Arc;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::sync::{broadcast, mpsc};
+use tokio_tungstenite::tungstenite::{Error, Message};
+use tokio_tungstenite::WebSocketStream;
+use tracing::{event, Level};
+
+/// Persistent connection to Kodi WebSocket endpoint (`ws://host:9090/jsonrpc` by default).
+///
+/// Each WebSocket message carries a single JSON-RPC message. Responses are matched to their
+/// request by `id`, so any number of requests can be in flight at the same time over a single
+/// connection.
+pub struct WebSocketTransport {
+    connection: Connection,
+}
+
+impl WebSocketTransport {
+    pub async fn connect(url: &str) -> Result<Self, Error> {
+        let (stream, _) = tokio_tungstenite::connect_async(url).await?;
+        let (connection, requests, multiplexer) = Connection::new();
+        tokio::spawn(run(stream, requests, multiplexer));
+        Ok(Self { connection })
+    }
+}
+
+#[async_trait]
+impl KodiTransport for WebSocketTransport {
+    async fn send(&self, id: usize, body: String) -> Result<String, KodiTransportError> {
+        self.connection.send(id, body).await
+    }
+}
+
+impl KodiNotificationTransport for WebSocketTransport {
+    fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.connection.subscribe()
+    }
+}
+
+async fn run<S>(
+    mut stream: WebSocketStream<S>,
+    mut requests: mpsc::UnboundedReceiver<String>,
+    multiplexer: Arc<Multiplexer>,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    loop {
+        tokio::select! {
+            request = requests.recv() => match request {
+                Some(body) => {
+                    event!(Level::DEBUG, "-> {body}", body = body);
+                    if let Err(err) = stream.send(Message::Text(body)).await {
+                        event!(Level::ERROR, "Could not write to kodi: {}", err);
+                        break;
+                    }
+                }
+                None => {
+                    let _ = stream.close(None).await;
+                    break;
+                }
+            },
+            message = stream.next() => match message {
+                Some(Ok(Message::Text(text))) => multiplexer.deliver(&text),
+                Some(Ok(Message::Binary(data))) => multiplexer.deliver(&String::from_utf8_lossy(&data)),
+                // Pings are answered by tungstenite itself
+                Some(Ok(Message::Ping(_))) | Some(Ok(Message::Pong(_))) => {}
+                Some(Ok(Message::Close(_))) | None => {
+                    event!(Level::DEBUG, "kodi has closed the connection");
+                    break;
+                }
+                Some(Err(err)) => {
+                    event!(Level::ERROR, "Could not read from kodi: {}", err);
+                    break;
+                }
+            },
+        }
+    }
+    multiplexer.close();
+}
+
+#[tokio::test]
+async fn test_websocket_transport() {
+    use crate::methods::{ApplicationSetVolume, JSONRPCVersion, JSONRPCVersionResponse};
+    use crate::KodiClient;
+    use tokio::net::TcpListener;
+
+    #[derive(serde::Deserialize)]
+    struct Request {
+        id: usize,
+        method: String,
+    }
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let server = tokio::spawn(async move {
+        let (socket, _) = listener.accept().await.unwrap();
+        let mut stream = tokio_tungstenite::accept_async(socket).await.unwrap();
+        let mut requests = Vec::new();
+        while requests.len() < 2 {
+            match stream.next().await.unwrap().unwrap() {
+                Message::Text(text) => {
+                    requests.push(serde_json::from_str::<Request>(&text).unwrap())
+                }
+                message => panic!("unexpected message {:?}", message),
+            }
+        }
+        // Answer in reverse order
+        for request in requests.iter().rev() {
+            let result = match request.method.as_str() {
+                "JSONRPC.Version" => r#"{"version":{"major":12,"minor":4,"patch":0}}"#,
+                "Application.SetVolume" => "42",
+                method => panic!("unexpected method {}", method),
+            };
+            stream
+                .send(Message::Text(format!(
+                    r#"{{"id":{},"jsonrpc":"2.0","result":{}}}"#,
+                    request.id, result
+                )))
+                .await
+                .unwrap();
+        }
+        stream.close(None).await.unwrap();
+    });
+
+    let url = format!("ws://{}/jsonrpc", addr);
+    let client = KodiClient::with_transport(WebSocketTransport::connect(&url).await.unwrap());
+    let (version, volume) = tokio::join!(
+        client.send_method(JSONRPCVersion {}),
+        client.send_method(ApplicationSetVolume { volume: 42 }),
+    );
+    let JSONRPCVersionResponse::Version { major, minor, .. } = version.unwrap();
+    assert_eq!((major, minor), (12, 4));
+    assert_eq!(volume.unwrap(), 42);
+
+    server.await.unwrap();
+    assert!(client.send_method(JSONRPCVersion {}).await.is_err());
+}
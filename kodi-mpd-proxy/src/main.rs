@@ -4,7 +4,9 @@ use chrono::{DateTime, FixedOffset, TimeZone};
 use clap::Clap;
 use enum_map::EnumMap;
 use enumset::EnumSet;
+use kodi_jsonrpc_client::http::{HttpConfigError, HttpTransport};
 use kodi_jsonrpc_client::methods::*;
+use kodi_jsonrpc_client::policy::{CircuitBreaker, KodiPolicy};
 use kodi_jsonrpc_client::types::list::item::FileType as KodiFileType;
 use kodi_jsonrpc_client::KodiClient;
 use mpd_server_protocol::{
@@ -725,92 +727,41 @@ impl CommandHandler for KodiProxyCommandHandler {
         filters: &[TagFilter],
         _sensitive: bool,
     ) -> Result<Vec<Song>, Box<dyn std::error::Error + Send + Sync>> {
-        use kodi_jsonrpc_client::types::list::filter::fields::Songs as SongsFields;
-        use kodi_jsonrpc_client::types::list::filter::rule::Songs as SongsRule;
-        use kodi_jsonrpc_client::types::list::filter::{Operators, Songs as SongsFiler};
+        use kodi_jsonrpc_client::query::{self, songs::*};
 
-        let mut filter: Option<SongsFiler> = None;
+        let mut query = query::songs();
         for tag_filter in filters {
+            let value = tag_filter.value.as_str();
             let item = match tag_filter.tag {
-                TagType::Album => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Album,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::AlbumArtist => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::AlbumArtist,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Artist => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Artist,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Comment => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Comment,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Date => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Year,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Disc => match usize::from_str_radix(&tag_filter.value, 10) {
-                    Ok(disc) => SongsFiler::Rule(SongsRule {
-                        field: SongsFields::TrackNumber,
-                        operator: Operators::Between,
-                        value: vec![
-                            (disc << 16).to_string(),
-                            ((disc << 16) + 0xffff - 1).to_string(),
-                        ]
-                        .into(),
-                    }),
-                    Err(_) => SongsFiler::Rule(SongsRule {
-                        field: SongsFields::TrackNumber,
-                        operator: Operators::Is,
-                        value: tag_filter.value.clone().into(),
-                    }),
+                TagType::Album => album().is(value),
+                TagType::AlbumArtist => album_artist().is(value),
+                TagType::Artist => artist().is(value),
+                TagType::Comment => comment().is(value),
+                TagType::Date => match value.parse::<u32>() {
+                    Ok(year_value) => year().is(year_value),
+                    Err(_) => year().is_text(value),
                 },
-                TagType::Genre => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Genre,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Title => SongsFiler::Rule(SongsRule {
-                    field: SongsFields::Title,
-                    operator: Operators::Is,
-                    value: tag_filter.value.clone().into(),
-                }),
-                TagType::Track => match u16::from_str_radix(&tag_filter.value, 10) {
-                    Ok(track) => SongsFiler::Rule(SongsRule {
-                        field: SongsFields::TrackNumber,
-                        operator: Operators::Is,
-                        // This will look through discs 1 to 64
-                        value: (1u8..=64u8)
-                            .map(|disc| (u32::from(disc) << 16 | u32::from(track)).to_string())
-                            .collect::<Vec<_>>()
-                            .into(),
-                    }),
-                    Err(_) => SongsFiler::Rule(SongsRule {
-                        field: SongsFields::TrackNumber,
-                        operator: Operators::Is,
-                        value: tag_filter.value.clone().into(),
-                    }),
+                TagType::Disc => match value.parse::<u16>() {
+                    Ok(disc) => {
+                        let disc = u32::from(disc) << 16;
+                        track_number().between(disc, disc + 0xffff)
+                    }
+                    Err(_) => track_number().is_text(value),
+                },
+                TagType::Genre => genre().is(value),
+                TagType::Title => title().is(value),
+                TagType::Track => match value.parse::<u16>() {
+                    // This will look through discs 1 to 64
+                    Ok(track) => {
+                        track_number().is_any((1u32..=64).map(|disc| disc << 16 | u32::from(track)))
+                    }
+                    Err(_) => track_number().is_text(value),
                 },
                 _ => return Err("Unsupported filter".into()),
             };
-            if let Some(filter) = &mut filter {
-                filter.and(item);
-            } else {
-                filter = Some(item);
-            }
-        }
-        let mut method = AudioLibraryGetSongs::all_properties();
-        if let Some(filter) = filter {
-            method.filter = Some(filter.into());
+            query = query.and(item);
         }
+        let method = AudioLibraryGetSongs::all_properties().with_query(query);
 
         let path_mapper = self.path_mapper().await;
 
@@ -881,13 +832,55 @@ impl CommandHandler for KodiProxyCommandHandler {
 #[derive(Clap)]
 #[clap(version = env!("CARGO_PKG_VERSION"), author = env!("CARGO_PKG_AUTHORS"))]
 struct Opts {
-    /// Sets kodi JSON-RPC endpoint
+    /// Sets kodi JSON-RPC endpoint, which may embed the web server credentials
     #[clap(short, long, default_value = "http://127.0.0.1:8080/jsonrpc")]
     kodi: reqwest::Url,
 
+    /// Reads the kodi web server credentials from a file holding `username:password`, instead
+    /// of the KODI_USERNAME and KODI_PASSWORD environment variables
+    #[clap(long)]
+    credentials_file: Option<PathBuf>,
+
+    /// Trusts the PEM certificate authority at this path
+    #[clap(long)]
+    ca_certificate: Option<PathBuf>,
+
+    /// Accepts self-signed or otherwise invalid certificates
+    #[clap(long)]
+    insecure: bool,
+
     /// Sets listening socket address
     #[clap(short, long, default_value = "127.0.0.1:6600")]
     listen: SocketAddr,
+
+    /// Sets the time in seconds after which a request to kodi is abandoned
+    #[clap(long, default_value = "10")]
+    timeout: u64,
+
+    /// Sets how many times requests only reading data from kodi are retried
+    #[clap(long, default_value = "2")]
+    retries: usize,
+}
+
+impl Opts {
+    fn kodi_transport(&self) -> Result<HttpTransport, HttpConfigError> {
+        let mut transport = HttpTransport::builder(self.kodi.clone())
+            .credentials_from(self.credentials_file.as_deref())?
+            .accept_invalid_certificates(self.insecure);
+        if let Some(path) = &self.ca_certificate {
+            transport = transport.ca_certificate(path);
+        }
+        transport.build()
+    }
+
+    fn kodi_client(&self, transport: &HttpTransport, breaker: &Arc<CircuitBreaker>) -> KodiClient {
+        let policy = KodiPolicy::default()
+            .with_timeout(Duration::from_secs(self.timeout))
+            .with_retries(self.retries, Duration::from_millis(200));
+        KodiClient::with_transport(transport.clone())
+            .with_policy(policy)
+            .with_circuit_breaker(breaker.clone())
+    }
 }
 
 #[tokio::main]
@@ -901,10 +894,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     let listener = TcpListener::bind(opts.listen).await?;
 
-    let kodi_client = KodiClient::new(
-        reqwest::Client::builder().build().unwrap(),
-        opts.kodi.clone(),
-    );
+    // Shared by all the clients so that they all fail fast once kodi is unreachable
+    let breaker = Arc::new(CircuitBreaker::new(3, Duration::from_secs(5)));
+    let transport = opts.kodi_transport()?;
+    let kodi_client = opts.kodi_client(&transport, &breaker);
 
     let (tx, rx) = watch::channel(0);
 
@@ -922,16 +915,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     loop {
         let (socket, _) = listener.accept().await?;
 
-        let kodi_url = opts.kodi.clone();
+        let kodi_client = opts.kodi_client(&transport, &breaker);
 
         let player = player.clone();
 
         let rx = rx.clone();
 
         tokio::spawn(async move {
-            let kodi_client =
-                KodiClient::new(reqwest::Client::builder().build().unwrap(), kodi_url);
-
             let mut server = Server::new(
                 BufReader::new(socket),
                 KodiProxyCommandHandler::new(kodi_client, player, rx),
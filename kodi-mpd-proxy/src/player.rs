@@ -37,11 +37,28 @@ impl KodiPlayer {
     pub async fn refresh(&self) {
         use kodi_jsonrpc_client::types::player::Type as PlayerType;
 
-        match self
+        let (app_props, player0, player1, player2) = match self
             .kodi_client
-            .send_method(ApplicationGetProperties::all())
+            .batch()
+            .add(ApplicationGetProperties::all())
+            .add(PlayerGetProperties::all(0))
+            .add(PlayerGetProperties::all(1))
+            .add(PlayerGetProperties::all(2))
+            .send()
             .await
         {
+            Ok(responses) => responses,
+            Err(err) => {
+                event!(
+                    Level::ERROR,
+                    "Count not retrieve properties of application and players: {}",
+                    err
+                );
+                return;
+            }
+        };
+
+        match app_props {
             Ok(props) => {
                 let changed =
                     self.volume().await != props.volume || self.muted().await != props.muted;
@@ -57,21 +74,21 @@ impl KodiPlayer {
             ),
         }
 
-        let mut ids = &mut [0u8, 1u8, 2u8][..];
-
-        let mut current = self.id();
+        let current = self.id();
         assert!(current <= 2);
 
-        while !ids.is_empty() {
-            let player_id = ids[current as usize];
-            match self
-                .kodi_client
-                .send_method(PlayerGetProperties::all(player_id))
-                .await
-            {
+        let mut players: Vec<_> = vec![player0, player1, player2]
+            .into_iter()
+            .enumerate()
+            .collect();
+        // try current player first
+        players.sort_by_key(|(player_id, _)| *player_id != current as usize);
+
+        for (player_id, props) in players {
+            match props {
                 Ok(props) => {
                     if props.kind == Some(PlayerType::Audio) {
-                        self.id.store(current, Ordering::Relaxed);
+                        self.id.store(player_id as u8, Ordering::Relaxed);
                         let changed = self.position().await != props.position
                             || self.speed().await != props.speed;
                         *self.player_properties.write().await = props;
@@ -89,13 +106,6 @@ impl KodiPlayer {
                     err
                 ),
             }
-            // put current player id at the end
-            ids.swap(current.into(), ids.len() - 1);
-            // remove last element from the list
-            let len = ids.len();
-            ids = &mut ids[..(len - 1)];
-            // use first id in the list as next player id to try
-            current = 0;
         }
     }
 